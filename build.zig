@@ -21,7 +21,9 @@ pub fn build(b: *std.Build) void {
 
     // Build Rust cryptographic library with native CPU optimizations
     // This ensures maximum performance for the Rust implementations
-    const cargo_build = b.addSystemCommand(&.{ "env", "RUSTFLAGS=-C target-cpu=native", "cargo", "build", "--release", "--manifest-path", "rust-crypto/Cargo.toml" });
+    // aes_armv8/polyval_armv8 enable the ARMv8 crypto backends of the aes and
+    // polyval crates (AES-NI/PCLMUL are autodetected; the cfgs are ignored on x86)
+    const cargo_build = b.addSystemCommand(&.{ "env", "RUSTFLAGS=-C target-cpu=native --cfg aes_armv8 --cfg polyval_armv8", "cargo", "build", "--release", "--manifest-path", "rust-crypto/Cargo.toml" });
 
     // ========================================================================
     // Zig Libraries
@@ -1,7 +1,87 @@
 // rust-crypto/src/lib.rs - Rust crypto implementations for benchmarking
-// Using the sha2 crate for SHA256 and SHA512 with hardware acceleration
+// - sha2: SHA-2 family with hardware acceleration (one-shot and streaming)
+// - sha3: Keccak-256, SHA3-256/512 and SHAKE128/256
+// - blake2: BLAKE2b/BLAKE2s (plain and keyed)
+// - blake3: BLAKE3 (SIMD, rayon-parallel, keyed, derive_key and XOF modes)
+// - hmac: HMAC-SHA256/512 (one-shot and precomputed-key contexts)
+// - hkdf: HKDF-SHA256/512 extract and expand
+// - pbkdf2: PBKDF2-HMAC-SHA256/512 with self-reported timing
+// - argon2: Argon2id/Argon2i/Argon2d raw hashing and PHC-string verify
+// - scrypt, bcrypt: legacy password hashing (bcrypt hash and verify)
+// - aes-gcm: AES-128-GCM and AES-256-GCM seal/open
+// - chacha20poly1305: ChaCha20-Poly1305 and XChaCha20-Poly1305 seal/open
+// - aes-gcm-siv, aes-siv: nonce-misuse-resistant AES-GCM-SIV and AES-SIV
+// - ctr, cbc, xts-mode: raw AES-128/256 CTR, CBC (PKCS#7) and XTS, in place
+// - poly1305, ghash, polyval: universal hashes (one-shot and streaming)
+// - cmac, tiny-keccak: AES-CMAC and KMAC128/256
+// - ed25519-dalek: Ed25519 keygen, sign (incl. expanded-key fast path), verify
+//   and batch verify
+// - x25519-dalek: X25519 public key derivation and key agreement
+// - k256: secp256k1 ECDSA (RFC 6979 sign, verify, public key recovery) and
+//   BIP-340 Schnorr signatures
+// - p256, p384: NIST P-256/P-384 ECDSA and ECDH with SEC1 key encodings
+// - rsa: RSA PKCS#1 v1.5 and PSS signatures, OAEP encryption (SHA-256)
+// - ml-kem: ML-KEM-512/768/1024 (FIPS 203) seeded keygen, encaps and decaps
+// - ml-dsa: ML-DSA-44/65/87 (FIPS 204) seeded keygen, deterministic and hedged
+//   sign, verify
 
-use sha2::{Digest, Sha256, Sha512};
+// Every export is called from Zig across the C ABI; pointer validity is the
+// caller's contract, exactly as with the Zig FFI wrappers in src/zig_ffi.zig.
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+
+use aes::cipher::block_padding::Pkcs7;
+use aes::cipher::{BlockDecryptMut, BlockEncryptMut, KeyIvInit, StreamCipher};
+use aes::{Aes128, Aes256};
+use aes_gcm::aead::generic_array::typenum::Unsigned;
+use aes_gcm::aead::generic_array::GenericArray;
+use aes_gcm::aead::AeadInPlace;
+use aes_gcm::{Aes128Gcm, Aes256Gcm};
+use aes_gcm_siv::{Aes128GcmSiv, Aes256GcmSiv};
+use aes_siv::siv::{Aes128Siv, Aes256Siv};
+use argon2::password_hash::{PasswordHash, PasswordVerifier};
+use argon2::{Argon2, Params};
+use blake2::digest::consts::U32;
+use blake2::digest::Mac;
+use blake2::{Blake2b, Blake2b512, Blake2bMac, Blake2bMac512, Blake2s256, Blake2sMac256};
+use chacha20poly1305::{ChaCha20Poly1305, XChaCha20Poly1305};
+use cmac::Cmac;
+use ed25519_dalek::hazmat::ExpandedSecretKey;
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use ghash::GHash;
+use hkdf::Hkdf;
+use hmac::Hmac;
+use k256::ecdsa::signature::Verifier;
+use k256::elliptic_curve::ops::{Invert, LinearCombination, Reduce};
+use k256::elliptic_curve::point::DecompressPoint;
+use ml_dsa::signature::rand_core::{TryCryptoRng, TryRng};
+use ml_dsa::{MlDsa44, MlDsa65, MlDsa87};
+use ml_kem::kem::Decapsulate;
+use ml_kem::{EncapsulateDeterministic, EncodedSizeUser, KemCore, MlKem1024, MlKem512, MlKem768};
+use poly1305::universal_hash::UniversalHash;
+use poly1305::Poly1305;
+use polyval::Polyval;
+use rsa::pkcs1::{DecodeRsaPrivateKey, DecodeRsaPublicKey};
+use rsa::pkcs8::{DecodePrivateKey, DecodePublicKey};
+use rsa::rand_core::OsRng;
+use rsa::{Oaep, Pkcs1v15Sign, Pss, RsaPrivateKey, RsaPublicKey};
+use sha2::{Digest, Sha224, Sha256, Sha384, Sha512, Sha512_224, Sha512_256};
+use sha3::digest::ExtendableOutput;
+use sha3::{Keccak256, Sha3_256, Sha3_512, Shake128, Shake256};
+use std::time::Instant;
+use tiny_keccak::{Hasher as KeccakHasher, Kmac};
+use x25519_dalek::{PublicKey, StaticSecret};
+
+type HmacSha256 = Hmac<Sha256>;
+type HmacSha512 = Hmac<Sha512>;
+
+/// Borrow a caller buffer that may be passed as (NULL, 0) when empty
+fn optional_slice<'a>(ptr: *const u8, len: usize) -> &'a [u8] {
+    if len == 0 {
+        &[]
+    } else {
+        unsafe { std::slice::from_raw_parts(ptr, len) }
+    }
+}
 
 /// SHA256 using sha2 crate with hardware acceleration
 #[no_mangle]
@@ -34,3 +114,3583 @@ pub extern "C" fn rust_sha512(data: *const u8, len: usize, output: *mut u8) {
         std::ptr::copy_nonoverlapping(result.as_ptr(), output, 64);
     }
 }
+
+/// SHA224 (truncated SHA256, 28-byte output) using sha2 crate with hardware acceleration
+#[no_mangle]
+pub extern "C" fn rust_sha224(data: *const u8, len: usize, output: *mut u8) {
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Create hasher and process data
+    let mut hasher = Sha224::new();
+    hasher.update(input);
+    let result = hasher.finalize();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), output, 28);
+    }
+}
+
+/// SHA384 (truncated SHA512, 48-byte output) using sha2 crate with hardware acceleration
+#[no_mangle]
+pub extern "C" fn rust_sha384(data: *const u8, len: usize, output: *mut u8) {
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Create hasher and process data
+    let mut hasher = Sha384::new();
+    hasher.update(input);
+    let result = hasher.finalize();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), output, 48);
+    }
+}
+
+/// SHA512/224 (28-byte output) using sha2 crate with hardware acceleration
+#[no_mangle]
+pub extern "C" fn rust_sha512_224(data: *const u8, len: usize, output: *mut u8) {
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Create hasher and process data
+    let mut hasher = Sha512_224::new();
+    hasher.update(input);
+    let result = hasher.finalize();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), output, 28);
+    }
+}
+
+/// SHA512/256 (32-byte output) using sha2 crate with hardware acceleration
+#[no_mangle]
+pub extern "C" fn rust_sha512_256(data: *const u8, len: usize, output: *mut u8) {
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Create hasher and process data
+    let mut hasher = Sha512_256::new();
+    hasher.update(input);
+    let result = hasher.finalize();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), output, 32);
+    }
+}
+
+/// Allocate a streaming SHA256 context; release it with `rust_sha256_free`
+#[no_mangle]
+pub extern "C" fn rust_sha256_new() -> *mut Sha256 {
+    Box::into_raw(Box::new(Sha256::new()))
+}
+
+/// Absorb `len` bytes into a streaming SHA256 context
+#[no_mangle]
+pub extern "C" fn rust_sha256_update(ctx: *mut Sha256, data: *const u8, len: usize) {
+    let hasher = unsafe { &mut *ctx };
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+    hasher.update(input);
+}
+
+/// Write the SHA256 digest of everything absorbed so far, then reset the
+/// context so it can be reused for the next message
+#[no_mangle]
+pub extern "C" fn rust_sha256_finalize(ctx: *mut Sha256, output: *mut u8) {
+    let hasher = unsafe { &mut *ctx };
+    let result = hasher.finalize_reset();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), output, 32);
+    }
+}
+
+/// Discard any absorbed input and return the context to its initial state
+#[no_mangle]
+pub extern "C" fn rust_sha256_reset(ctx: *mut Sha256) {
+    let hasher = unsafe { &mut *ctx };
+    Digest::reset(hasher);
+}
+
+/// Free a context returned by `rust_sha256_new` (NULL is ignored)
+#[no_mangle]
+pub extern "C" fn rust_sha256_free(ctx: *mut Sha256) {
+    if !ctx.is_null() {
+        drop(unsafe { Box::from_raw(ctx) });
+    }
+}
+
+/// Allocate a streaming SHA512 context; release it with `rust_sha512_free`
+#[no_mangle]
+pub extern "C" fn rust_sha512_new() -> *mut Sha512 {
+    Box::into_raw(Box::new(Sha512::new()))
+}
+
+/// Absorb `len` bytes into a streaming SHA512 context
+#[no_mangle]
+pub extern "C" fn rust_sha512_update(ctx: *mut Sha512, data: *const u8, len: usize) {
+    let hasher = unsafe { &mut *ctx };
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+    hasher.update(input);
+}
+
+/// Write the SHA512 digest of everything absorbed so far, then reset the
+/// context so it can be reused for the next message
+#[no_mangle]
+pub extern "C" fn rust_sha512_finalize(ctx: *mut Sha512, output: *mut u8) {
+    let hasher = unsafe { &mut *ctx };
+    let result = hasher.finalize_reset();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), output, 64);
+    }
+}
+
+/// Discard any absorbed input and return the context to its initial state
+#[no_mangle]
+pub extern "C" fn rust_sha512_reset(ctx: *mut Sha512) {
+    let hasher = unsafe { &mut *ctx };
+    Digest::reset(hasher);
+}
+
+/// Free a context returned by `rust_sha512_new` (NULL is ignored)
+#[no_mangle]
+pub extern "C" fn rust_sha512_free(ctx: *mut Sha512) {
+    if !ctx.is_null() {
+        drop(unsafe { Box::from_raw(ctx) });
+    }
+}
+
+/// Keccak-256 (original Keccak padding, as used by Ethereum) using sha3 crate
+#[no_mangle]
+pub extern "C" fn rust_keccak256(data: *const u8, len: usize, output: *mut u8) {
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Create hasher and process data
+    let mut hasher = Keccak256::new();
+    hasher.update(input);
+    let result = hasher.finalize();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), output, 32);
+    }
+}
+
+/// SHA3-256 (FIPS 202) using sha3 crate
+#[no_mangle]
+pub extern "C" fn rust_sha3_256(data: *const u8, len: usize, output: *mut u8) {
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Create hasher and process data
+    let mut hasher = Sha3_256::new();
+    hasher.update(input);
+    let result = hasher.finalize();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), output, 32);
+    }
+}
+
+/// SHA3-512 (FIPS 202) using sha3 crate
+#[no_mangle]
+pub extern "C" fn rust_sha3_512(data: *const u8, len: usize, output: *mut u8) {
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Create hasher and process data
+    let mut hasher = Sha3_512::new();
+    hasher.update(input);
+    let result = hasher.finalize();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), output, 64);
+    }
+}
+
+/// SHAKE128 (FIPS 202 XOF) using sha3 crate, squeezing `out_len` bytes
+#[no_mangle]
+pub extern "C" fn rust_shake128(data: *const u8, len: usize, output: *mut u8, out_len: usize) {
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+    let out = unsafe { std::slice::from_raw_parts_mut(output, out_len) };
+
+    // Absorb input, then squeeze directly into the output buffer
+    Shake128::digest_xof(input, out);
+}
+
+/// SHAKE256 (FIPS 202 XOF) using sha3 crate, squeezing `out_len` bytes
+#[no_mangle]
+pub extern "C" fn rust_shake256(data: *const u8, len: usize, output: *mut u8, out_len: usize) {
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+    let out = unsafe { std::slice::from_raw_parts_mut(output, out_len) };
+
+    // Absorb input, then squeeze directly into the output buffer
+    Shake256::digest_xof(input, out);
+}
+
+/// BLAKE2b-512 using blake2 crate
+#[no_mangle]
+pub extern "C" fn rust_blake2b512(data: *const u8, len: usize, output: *mut u8) {
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Create hasher and process data
+    let mut hasher = Blake2b512::new();
+    hasher.update(input);
+    let result = hasher.finalize();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), output, 64);
+    }
+}
+
+/// BLAKE2b-256 (BLAKE2b with 32-byte output parameter) using blake2 crate
+#[no_mangle]
+pub extern "C" fn rust_blake2b256(data: *const u8, len: usize, output: *mut u8) {
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Create hasher and process data
+    let mut hasher = Blake2b::<U32>::new();
+    hasher.update(input);
+    let result = hasher.finalize();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), output, 32);
+    }
+}
+
+/// BLAKE2s-256 using blake2 crate
+#[no_mangle]
+pub extern "C" fn rust_blake2s256(data: *const u8, len: usize, output: *mut u8) {
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Create hasher and process data
+    let mut hasher = Blake2s256::new();
+    hasher.update(input);
+    let result = hasher.finalize();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), output, 32);
+    }
+}
+
+/// Keyed BLAKE2b-512 MAC with salt and personalization using blake2 crate
+///
+/// Key must be 1-64 bytes; salt and personalization may be empty (NULL, 0)
+/// and are zero-padded up to 16 bytes. Returns 0 on success, -1 if any
+/// length is out of range.
+#[no_mangle]
+pub extern "C" fn rust_blake2b512_mac(
+    key: *const u8,
+    key_len: usize,
+    salt: *const u8,
+    salt_len: usize,
+    personal: *const u8,
+    personal_len: usize,
+    data: *const u8,
+    len: usize,
+    output: *mut u8,
+) -> i32 {
+    if key_len == 0 || key_len > 64 || salt_len > 16 || personal_len > 16 {
+        return -1;
+    }
+    let key = unsafe { std::slice::from_raw_parts(key, key_len) };
+    let salt = optional_slice(salt, salt_len);
+    let personal = optional_slice(personal, personal_len);
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Keyed parameter block setup, then process data
+    let Ok(mut mac) = Blake2bMac512::new_with_salt_and_personal(key, salt, personal) else {
+        return -1;
+    };
+    mac.update(input);
+    let result = mac.finalize().into_bytes();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), output, 64);
+    }
+    0
+}
+
+/// Keyed BLAKE2b-256 MAC with salt and personalization using blake2 crate
+///
+/// Key must be 1-64 bytes; salt and personalization may be empty (NULL, 0)
+/// and are zero-padded up to 16 bytes. Returns 0 on success, -1 if any
+/// length is out of range.
+#[no_mangle]
+pub extern "C" fn rust_blake2b256_mac(
+    key: *const u8,
+    key_len: usize,
+    salt: *const u8,
+    salt_len: usize,
+    personal: *const u8,
+    personal_len: usize,
+    data: *const u8,
+    len: usize,
+    output: *mut u8,
+) -> i32 {
+    if key_len == 0 || key_len > 64 || salt_len > 16 || personal_len > 16 {
+        return -1;
+    }
+    let key = unsafe { std::slice::from_raw_parts(key, key_len) };
+    let salt = optional_slice(salt, salt_len);
+    let personal = optional_slice(personal, personal_len);
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Keyed parameter block setup, then process data
+    let Ok(mut mac) = Blake2bMac::<U32>::new_with_salt_and_personal(key, salt, personal) else {
+        return -1;
+    };
+    mac.update(input);
+    let result = mac.finalize().into_bytes();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), output, 32);
+    }
+    0
+}
+
+/// Keyed BLAKE2s-256 MAC with salt and personalization using blake2 crate
+///
+/// Key must be 1-32 bytes; salt and personalization may be empty (NULL, 0)
+/// and are zero-padded up to 8 bytes. Returns 0 on success, -1 if any
+/// length is out of range.
+#[no_mangle]
+pub extern "C" fn rust_blake2s256_mac(
+    key: *const u8,
+    key_len: usize,
+    salt: *const u8,
+    salt_len: usize,
+    personal: *const u8,
+    personal_len: usize,
+    data: *const u8,
+    len: usize,
+    output: *mut u8,
+) -> i32 {
+    if key_len == 0 || key_len > 32 || salt_len > 8 || personal_len > 8 {
+        return -1;
+    }
+    let key = unsafe { std::slice::from_raw_parts(key, key_len) };
+    let salt = optional_slice(salt, salt_len);
+    let personal = optional_slice(personal, personal_len);
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Keyed parameter block setup, then process data
+    let Ok(mut mac) = Blake2sMac256::new_with_salt_and_personal(key, salt, personal) else {
+        return -1;
+    };
+    mac.update(input);
+    let result = mac.finalize().into_bytes();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), output, 32);
+    }
+    0
+}
+
+/// BLAKE3 using blake3 crate (single-threaded, SIMD autodetected)
+#[no_mangle]
+pub extern "C" fn rust_blake3(data: *const u8, len: usize, output: *mut u8) {
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Create hasher and process data
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(input);
+    let result = hasher.finalize();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_bytes().as_ptr(), output, 32);
+    }
+}
+
+/// BLAKE3 using blake3 crate, splitting the input across the rayon thread pool
+#[no_mangle]
+pub extern "C" fn rust_blake3_rayon(data: *const u8, len: usize, output: *mut u8) {
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Create hasher and process data on all cores
+    let mut hasher = blake3::Hasher::new();
+    hasher.update_rayon(input);
+    let result = hasher.finalize();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_bytes().as_ptr(), output, 32);
+    }
+}
+
+/// BLAKE3 keyed hash (MAC mode) with a 32-byte key using blake3 crate
+#[no_mangle]
+pub extern "C" fn rust_blake3_keyed(key: *const u8, data: *const u8, len: usize, output: *mut u8) {
+    let key = unsafe { &*(key as *const [u8; 32]) };
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Create keyed hasher and process data
+    let mut hasher = blake3::Hasher::new_keyed(key);
+    hasher.update(input);
+    let result = hasher.finalize();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_bytes().as_ptr(), output, 32);
+    }
+}
+
+/// BLAKE3 derive_key mode using blake3 crate, writing a 32-byte derived key
+///
+/// The context string must be valid UTF-8 and may be passed as (NULL, 0) when
+/// empty. Returns 0 on success, -1 otherwise.
+#[no_mangle]
+pub extern "C" fn rust_blake3_derive_key(
+    context: *const u8,
+    context_len: usize,
+    key_material: *const u8,
+    len: usize,
+    output: *mut u8,
+) -> i32 {
+    let context = optional_slice(context, context_len);
+    let Ok(context) = std::str::from_utf8(context) else {
+        return -1;
+    };
+    let input = unsafe { std::slice::from_raw_parts(key_material, len) };
+
+    // Create derive_key hasher and process key material
+    let mut hasher = blake3::Hasher::new_derive_key(context);
+    hasher.update(input);
+    let result = hasher.finalize();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_bytes().as_ptr(), output, 32);
+    }
+    0
+}
+
+/// BLAKE3 extendable output using blake3 crate, squeezing `out_len` bytes
+#[no_mangle]
+pub extern "C" fn rust_blake3_xof(data: *const u8, len: usize, output: *mut u8, out_len: usize) {
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+    let out = unsafe { std::slice::from_raw_parts_mut(output, out_len) };
+
+    // Absorb input, then squeeze directly into the output buffer
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(input);
+    hasher.finalize_xof().fill(out);
+}
+
+/// HMAC-SHA256 using hmac crate (key setup and MAC in one call)
+#[no_mangle]
+pub extern "C" fn rust_hmac_sha256(
+    key: *const u8,
+    key_len: usize,
+    data: *const u8,
+    len: usize,
+    output: *mut u8,
+) {
+    let key = optional_slice(key, key_len);
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Derive inner/outer pads from the key, then process data
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(input);
+    let result = mac.finalize().into_bytes();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), output, 32);
+    }
+}
+
+/// Precompute the HMAC-SHA256 inner and outer pad states for a key;
+/// release the context with `rust_hmac_sha256_free`
+#[no_mangle]
+pub extern "C" fn rust_hmac_sha256_new(key: *const u8, key_len: usize) -> *mut HmacSha256 {
+    let key = optional_slice(key, key_len);
+    let mac = HmacSha256::new_from_slice(key).expect("HMAC accepts any key length");
+    Box::into_raw(Box::new(mac))
+}
+
+/// HMAC-SHA256 of one message under a precomputed key context
+///
+/// The context is left untouched, so it can MAC any number of messages.
+#[no_mangle]
+pub extern "C" fn rust_hmac_sha256_mac(
+    ctx: *const HmacSha256,
+    data: *const u8,
+    len: usize,
+    output: *mut u8,
+) {
+    let keyed = unsafe { &*ctx };
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Start from the precomputed pad states, then process data
+    let mut mac = keyed.clone();
+    mac.update(input);
+    let result = mac.finalize().into_bytes();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), output, 32);
+    }
+}
+
+/// Free a context returned by `rust_hmac_sha256_new` (NULL is ignored)
+#[no_mangle]
+pub extern "C" fn rust_hmac_sha256_free(ctx: *mut HmacSha256) {
+    if !ctx.is_null() {
+        drop(unsafe { Box::from_raw(ctx) });
+    }
+}
+
+/// HMAC-SHA512 using hmac crate (key setup and MAC in one call)
+#[no_mangle]
+pub extern "C" fn rust_hmac_sha512(
+    key: *const u8,
+    key_len: usize,
+    data: *const u8,
+    len: usize,
+    output: *mut u8,
+) {
+    let key = optional_slice(key, key_len);
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Derive inner/outer pads from the key, then process data
+    let mut mac = HmacSha512::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(input);
+    let result = mac.finalize().into_bytes();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), output, 64);
+    }
+}
+
+/// Precompute the HMAC-SHA512 inner and outer pad states for a key;
+/// release the context with `rust_hmac_sha512_free`
+#[no_mangle]
+pub extern "C" fn rust_hmac_sha512_new(key: *const u8, key_len: usize) -> *mut HmacSha512 {
+    let key = optional_slice(key, key_len);
+    let mac = HmacSha512::new_from_slice(key).expect("HMAC accepts any key length");
+    Box::into_raw(Box::new(mac))
+}
+
+/// HMAC-SHA512 of one message under a precomputed key context
+///
+/// The context is left untouched, so it can MAC any number of messages.
+#[no_mangle]
+pub extern "C" fn rust_hmac_sha512_mac(
+    ctx: *const HmacSha512,
+    data: *const u8,
+    len: usize,
+    output: *mut u8,
+) {
+    let keyed = unsafe { &*ctx };
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Start from the precomputed pad states, then process data
+    let mut mac = keyed.clone();
+    mac.update(input);
+    let result = mac.finalize().into_bytes();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), output, 64);
+    }
+}
+
+/// Free a context returned by `rust_hmac_sha512_new` (NULL is ignored)
+#[no_mangle]
+pub extern "C" fn rust_hmac_sha512_free(ctx: *mut HmacSha512) {
+    if !ctx.is_null() {
+        drop(unsafe { Box::from_raw(ctx) });
+    }
+}
+
+/// HKDF-SHA256 extract using hkdf crate, writing the 32-byte PRK
+///
+/// Salt may be empty (NULL, 0), which RFC 5869 treats as 32 zero bytes.
+#[no_mangle]
+pub extern "C" fn rust_hkdf_sha256_extract(
+    salt: *const u8,
+    salt_len: usize,
+    ikm: *const u8,
+    ikm_len: usize,
+    prk: *mut u8,
+) {
+    let salt = optional_slice(salt, salt_len);
+    let ikm = optional_slice(ikm, ikm_len);
+
+    // HMAC the input keying material under the salt
+    let (result, _) = Hkdf::<Sha256>::extract(Some(salt), ikm);
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), prk, 32);
+    }
+}
+
+/// HKDF-SHA256 expand using hkdf crate, writing `out_len` bytes of OKM
+///
+/// Returns 0 on success, -1 if the PRK is shorter than 32 bytes or
+/// `out_len` exceeds 255 * 32.
+#[no_mangle]
+pub extern "C" fn rust_hkdf_sha256_expand(
+    prk: *const u8,
+    prk_len: usize,
+    info: *const u8,
+    info_len: usize,
+    output: *mut u8,
+    out_len: usize,
+) -> i32 {
+    let prk = optional_slice(prk, prk_len);
+    let info = optional_slice(info, info_len);
+    let okm = unsafe { std::slice::from_raw_parts_mut(output, out_len) };
+
+    // Load the PRK, then expand directly into the output buffer
+    let Ok(hkdf) = Hkdf::<Sha256>::from_prk(prk) else {
+        return -1;
+    };
+    match hkdf.expand(info, okm) {
+        Ok(()) => 0,
+        Err(_) => -1,
+    }
+}
+
+/// HKDF-SHA512 extract using hkdf crate, writing the 64-byte PRK
+///
+/// Salt may be empty (NULL, 0), which RFC 5869 treats as 64 zero bytes.
+#[no_mangle]
+pub extern "C" fn rust_hkdf_sha512_extract(
+    salt: *const u8,
+    salt_len: usize,
+    ikm: *const u8,
+    ikm_len: usize,
+    prk: *mut u8,
+) {
+    let salt = optional_slice(salt, salt_len);
+    let ikm = optional_slice(ikm, ikm_len);
+
+    // HMAC the input keying material under the salt
+    let (result, _) = Hkdf::<Sha512>::extract(Some(salt), ikm);
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), prk, 64);
+    }
+}
+
+/// HKDF-SHA512 expand using hkdf crate, writing `out_len` bytes of OKM
+///
+/// Returns 0 on success, -1 if the PRK is shorter than 64 bytes or
+/// `out_len` exceeds 255 * 64.
+#[no_mangle]
+pub extern "C" fn rust_hkdf_sha512_expand(
+    prk: *const u8,
+    prk_len: usize,
+    info: *const u8,
+    info_len: usize,
+    output: *mut u8,
+    out_len: usize,
+) -> i32 {
+    let prk = optional_slice(prk, prk_len);
+    let info = optional_slice(info, info_len);
+    let okm = unsafe { std::slice::from_raw_parts_mut(output, out_len) };
+
+    // Load the PRK, then expand directly into the output buffer
+    let Ok(hkdf) = Hkdf::<Sha512>::from_prk(prk) else {
+        return -1;
+    };
+    match hkdf.expand(info, okm) {
+        Ok(()) => 0,
+        Err(_) => -1,
+    }
+}
+
+/// PBKDF2-HMAC-SHA256 using pbkdf2 crate, writing `out_len` bytes of key
+///
+/// Iteration counts don't fit the harness's size sweep, so the time spent
+/// deriving (monotonic clock, nanoseconds) is written to `elapsed_ns` unless
+/// it is NULL. Returns 0 on success, -1 if `rounds` is zero.
+#[no_mangle]
+pub extern "C" fn rust_pbkdf2_hmac_sha256(
+    password: *const u8,
+    password_len: usize,
+    salt: *const u8,
+    salt_len: usize,
+    rounds: u32,
+    output: *mut u8,
+    out_len: usize,
+    elapsed_ns: *mut u64,
+) -> i32 {
+    if rounds == 0 {
+        return -1;
+    }
+    let password = optional_slice(password, password_len);
+    let salt = optional_slice(salt, salt_len);
+    let key = unsafe { std::slice::from_raw_parts_mut(output, out_len) };
+
+    // Derive directly into the output buffer, timing only the derivation
+    let start = Instant::now();
+    pbkdf2::pbkdf2_hmac::<Sha256>(password, salt, rounds, key);
+    let elapsed = start.elapsed();
+
+    if !elapsed_ns.is_null() {
+        unsafe {
+            *elapsed_ns = elapsed.as_nanos() as u64;
+        }
+    }
+    0
+}
+
+/// PBKDF2-HMAC-SHA512 using pbkdf2 crate, writing `out_len` bytes of key
+///
+/// Iteration counts don't fit the harness's size sweep, so the time spent
+/// deriving (monotonic clock, nanoseconds) is written to `elapsed_ns` unless
+/// it is NULL. Returns 0 on success, -1 if `rounds` is zero.
+#[no_mangle]
+pub extern "C" fn rust_pbkdf2_hmac_sha512(
+    password: *const u8,
+    password_len: usize,
+    salt: *const u8,
+    salt_len: usize,
+    rounds: u32,
+    output: *mut u8,
+    out_len: usize,
+    elapsed_ns: *mut u64,
+) -> i32 {
+    if rounds == 0 {
+        return -1;
+    }
+    let password = optional_slice(password, password_len);
+    let salt = optional_slice(salt, salt_len);
+    let key = unsafe { std::slice::from_raw_parts_mut(output, out_len) };
+
+    // Derive directly into the output buffer, timing only the derivation
+    let start = Instant::now();
+    pbkdf2::pbkdf2_hmac::<Sha512>(password, salt, rounds, key);
+    let elapsed = start.elapsed();
+
+    if !elapsed_ns.is_null() {
+        unsafe {
+            *elapsed_ns = elapsed.as_nanos() as u64;
+        }
+    }
+    0
+}
+
+/// Shared body of the Argon2 exports: raw hash with explicit cost parameters
+#[allow(clippy::too_many_arguments)]
+fn argon2_hash(
+    algorithm: argon2::Algorithm,
+    password: *const u8,
+    password_len: usize,
+    salt: *const u8,
+    salt_len: usize,
+    m_cost: u32,
+    t_cost: u32,
+    parallelism: u32,
+    output: *mut u8,
+    out_len: usize,
+) -> i32 {
+    let password = optional_slice(password, password_len);
+    let salt = optional_slice(salt, salt_len);
+    let out = unsafe { std::slice::from_raw_parts_mut(output, out_len) };
+
+    // Validate parameters (m_cost in KiB, at least 8 * parallelism)
+    let Ok(params) = Params::new(m_cost, t_cost, parallelism, Some(out_len)) else {
+        return -1;
+    };
+    let argon2 = Argon2::new(algorithm, argon2::Version::V0x13, params);
+
+    // Fill memory and hash directly into the output buffer
+    match argon2.hash_password_into(password, salt, out) {
+        Ok(()) => 0,
+        Err(_) => -1,
+    }
+}
+
+/// Argon2id (v1.3) using argon2 crate
+///
+/// `m_cost` is in KiB. The crate processes lanes sequentially, so
+/// `parallelism` > 1 changes the output but not the thread count.
+/// Returns 0 on success, -1 if a parameter is out of range (e.g. salt
+/// shorter than 8 bytes or output shorter than 4).
+#[no_mangle]
+pub extern "C" fn rust_argon2id(
+    password: *const u8,
+    password_len: usize,
+    salt: *const u8,
+    salt_len: usize,
+    m_cost: u32,
+    t_cost: u32,
+    parallelism: u32,
+    output: *mut u8,
+    out_len: usize,
+) -> i32 {
+    argon2_hash(
+        argon2::Algorithm::Argon2id,
+        password,
+        password_len,
+        salt,
+        salt_len,
+        m_cost,
+        t_cost,
+        parallelism,
+        output,
+        out_len,
+    )
+}
+
+/// Argon2i (v1.3) using argon2 crate; parameters as for `rust_argon2id`
+#[no_mangle]
+pub extern "C" fn rust_argon2i(
+    password: *const u8,
+    password_len: usize,
+    salt: *const u8,
+    salt_len: usize,
+    m_cost: u32,
+    t_cost: u32,
+    parallelism: u32,
+    output: *mut u8,
+    out_len: usize,
+) -> i32 {
+    argon2_hash(
+        argon2::Algorithm::Argon2i,
+        password,
+        password_len,
+        salt,
+        salt_len,
+        m_cost,
+        t_cost,
+        parallelism,
+        output,
+        out_len,
+    )
+}
+
+/// Argon2d (v1.3) using argon2 crate; parameters as for `rust_argon2id`
+#[no_mangle]
+pub extern "C" fn rust_argon2d(
+    password: *const u8,
+    password_len: usize,
+    salt: *const u8,
+    salt_len: usize,
+    m_cost: u32,
+    t_cost: u32,
+    parallelism: u32,
+    output: *mut u8,
+    out_len: usize,
+) -> i32 {
+    argon2_hash(
+        argon2::Algorithm::Argon2d,
+        password,
+        password_len,
+        salt,
+        salt_len,
+        m_cost,
+        t_cost,
+        parallelism,
+        output,
+        out_len,
+    )
+}
+
+/// Verify a password against an Argon2 PHC string (`$argon2id$v=19$m=...`)
+///
+/// The variant and cost parameters are taken from the string. Returns 0 if
+/// the password matches, -1 on mismatch or a malformed string.
+#[no_mangle]
+pub extern "C" fn rust_argon2_verify(
+    phc: *const u8,
+    phc_len: usize,
+    password: *const u8,
+    password_len: usize,
+) -> i32 {
+    let phc = unsafe { std::slice::from_raw_parts(phc, phc_len) };
+    let password = optional_slice(password, password_len);
+
+    // Parse the PHC string, then recompute and compare in constant time
+    let Ok(phc) = std::str::from_utf8(phc) else {
+        return -1;
+    };
+    let Ok(hash) = PasswordHash::new(phc) else {
+        return -1;
+    };
+    match Argon2::default().verify_password(password, &hash) {
+        Ok(()) => 0,
+        Err(_) => -1,
+    }
+}
+
+/// scrypt using scrypt crate, writing `out_len` bytes of key
+///
+/// N = 2^`log_n`. Any `out_len` from 1 to (2^32 - 1) * 32 is accepted.
+/// Returns 0 on success, -1 if the parameters or output length are invalid.
+#[no_mangle]
+pub extern "C" fn rust_scrypt(
+    password: *const u8,
+    password_len: usize,
+    salt: *const u8,
+    salt_len: usize,
+    log_n: u8,
+    r: u32,
+    p: u32,
+    output: *mut u8,
+    out_len: usize,
+) -> i32 {
+    let password = optional_slice(password, password_len);
+    let salt = optional_slice(salt, salt_len);
+    let out = unsafe { std::slice::from_raw_parts_mut(output, out_len) };
+
+    // Validate parameters, then derive directly into the output buffer. The
+    // length in `Params` only bounds PHC strings; scrypt() fills `out` as-is.
+    let Ok(params) = scrypt::Params::new(log_n, r, p, scrypt::Params::RECOMMENDED_LEN) else {
+        return -1;
+    };
+    match scrypt::scrypt(password, salt, &params, out) {
+        Ok(()) => 0,
+        Err(_) => -1,
+    }
+}
+
+/// bcrypt using bcrypt crate with a caller-supplied 16-byte salt
+///
+/// Writes the 60-byte `$2b$` modular crypt string (not NUL-terminated).
+/// Passwords longer than 72 bytes are truncated, as bcrypt specifies.
+/// Returns 0 on success, -1 if `cost` is outside 4..=31.
+#[no_mangle]
+pub extern "C" fn rust_bcrypt_hash(
+    password: *const u8,
+    password_len: usize,
+    cost: u32,
+    salt: *const u8,
+    output: *mut u8,
+) -> i32 {
+    let password = optional_slice(password, password_len);
+    let salt = unsafe { *(salt as *const [u8; 16]) };
+
+    // Run EksBlowfish setup and encode the result
+    let Ok(parts) = bcrypt::hash_with_salt(password, cost, salt) else {
+        return -1;
+    };
+    let result = parts.format_for_version(bcrypt::Version::TwoB);
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), output, 60);
+    }
+    0
+}
+
+/// Verify a password against a bcrypt modular crypt string (`$2b$12$...`)
+///
+/// Returns 0 if the password matches, -1 on mismatch or a malformed string.
+#[no_mangle]
+pub extern "C" fn rust_bcrypt_verify(
+    hash: *const u8,
+    hash_len: usize,
+    password: *const u8,
+    password_len: usize,
+) -> i32 {
+    let hash = unsafe { std::slice::from_raw_parts(hash, hash_len) };
+    let password = optional_slice(password, password_len);
+
+    // Parse the hash string, then recompute and compare
+    let Ok(hash) = std::str::from_utf8(hash) else {
+        return -1;
+    };
+    match bcrypt::verify(password, hash) {
+        Ok(true) => 0,
+        _ => -1,
+    }
+}
+
+/// Shared body of the AEAD seal exports: detached-tag encryption
+///
+/// The plaintext is copied into `output` (which may alias it) and encrypted
+/// in place there; key schedule setup is included in the measured cost.
+#[allow(clippy::too_many_arguments)]
+fn aead_seal<A: AeadInPlace + aes_gcm::KeyInit>(
+    key: *const u8,
+    nonce: *const u8,
+    aad: *const u8,
+    aad_len: usize,
+    plaintext: *const u8,
+    len: usize,
+    output: *mut u8,
+    tag: *mut u8,
+) -> i32 {
+    let key = unsafe { std::slice::from_raw_parts(key, A::KeySize::USIZE) };
+    let nonce = unsafe { std::slice::from_raw_parts(nonce, A::NonceSize::USIZE) };
+    let aad = optional_slice(aad, aad_len);
+    let buffer = unsafe {
+        std::ptr::copy(plaintext, output, len);
+        std::slice::from_raw_parts_mut(output, len)
+    };
+
+    // Expand key, then encrypt and authenticate in place
+    let cipher = A::new(GenericArray::from_slice(key));
+    let Ok(result) = cipher.encrypt_in_place_detached(GenericArray::from_slice(nonce), aad, buffer)
+    else {
+        return -1;
+    };
+
+    // Copy tag to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), tag, A::TagSize::USIZE);
+    }
+    0
+}
+
+/// Shared body of the AEAD open exports: detached-tag decryption
+///
+/// Returns 0 on success, -1 on tag mismatch; on mismatch the contents of
+/// `output` are unspecified and must not be used.
+#[allow(clippy::too_many_arguments)]
+fn aead_open<A: AeadInPlace + aes_gcm::KeyInit>(
+    key: *const u8,
+    nonce: *const u8,
+    aad: *const u8,
+    aad_len: usize,
+    ciphertext: *const u8,
+    len: usize,
+    tag: *const u8,
+    output: *mut u8,
+) -> i32 {
+    let key = unsafe { std::slice::from_raw_parts(key, A::KeySize::USIZE) };
+    let nonce = unsafe { std::slice::from_raw_parts(nonce, A::NonceSize::USIZE) };
+    let tag = unsafe { std::slice::from_raw_parts(tag, A::TagSize::USIZE) };
+    let aad = optional_slice(aad, aad_len);
+    let buffer = unsafe {
+        std::ptr::copy(ciphertext, output, len);
+        std::slice::from_raw_parts_mut(output, len)
+    };
+
+    // Expand key, then verify and decrypt in place
+    let cipher = A::new(GenericArray::from_slice(key));
+    match cipher.decrypt_in_place_detached(
+        GenericArray::from_slice(nonce),
+        aad,
+        buffer,
+        GenericArray::from_slice(tag),
+    ) {
+        Ok(()) => 0,
+        Err(_) => -1,
+    }
+}
+
+/// AES-128-GCM seal using aes-gcm crate (16-byte key, 12-byte nonce, 16-byte tag)
+///
+/// `output` receives `len` bytes of ciphertext and may alias `plaintext`.
+/// Returns 0 on success, -1 if the message is too long.
+#[no_mangle]
+pub extern "C" fn rust_aes128gcm_seal(
+    key: *const u8,
+    nonce: *const u8,
+    aad: *const u8,
+    aad_len: usize,
+    plaintext: *const u8,
+    len: usize,
+    output: *mut u8,
+    tag: *mut u8,
+) -> i32 {
+    aead_seal::<Aes128Gcm>(key, nonce, aad, aad_len, plaintext, len, output, tag)
+}
+
+/// AES-128-GCM open using aes-gcm crate
+///
+/// `output` receives `len` bytes of plaintext and may alias `ciphertext`.
+/// Returns 0 on success, -1 on tag mismatch.
+#[no_mangle]
+pub extern "C" fn rust_aes128gcm_open(
+    key: *const u8,
+    nonce: *const u8,
+    aad: *const u8,
+    aad_len: usize,
+    ciphertext: *const u8,
+    len: usize,
+    tag: *const u8,
+    output: *mut u8,
+) -> i32 {
+    aead_open::<Aes128Gcm>(key, nonce, aad, aad_len, ciphertext, len, tag, output)
+}
+
+/// AES-256-GCM seal using aes-gcm crate (32-byte key, 12-byte nonce, 16-byte tag)
+///
+/// `output` receives `len` bytes of ciphertext and may alias `plaintext`.
+/// Returns 0 on success, -1 if the message is too long.
+#[no_mangle]
+pub extern "C" fn rust_aes256gcm_seal(
+    key: *const u8,
+    nonce: *const u8,
+    aad: *const u8,
+    aad_len: usize,
+    plaintext: *const u8,
+    len: usize,
+    output: *mut u8,
+    tag: *mut u8,
+) -> i32 {
+    aead_seal::<Aes256Gcm>(key, nonce, aad, aad_len, plaintext, len, output, tag)
+}
+
+/// AES-256-GCM open using aes-gcm crate
+///
+/// `output` receives `len` bytes of plaintext and may alias `ciphertext`.
+/// Returns 0 on success, -1 on tag mismatch.
+#[no_mangle]
+pub extern "C" fn rust_aes256gcm_open(
+    key: *const u8,
+    nonce: *const u8,
+    aad: *const u8,
+    aad_len: usize,
+    ciphertext: *const u8,
+    len: usize,
+    tag: *const u8,
+    output: *mut u8,
+) -> i32 {
+    aead_open::<Aes256Gcm>(key, nonce, aad, aad_len, ciphertext, len, tag, output)
+}
+
+/// ChaCha20-Poly1305 (RFC 8439) seal using chacha20poly1305 crate (32-byte key, 12-byte nonce, 16-byte tag)
+///
+/// `output` receives `len` bytes of ciphertext and may alias `plaintext`.
+/// Returns 0 on success, -1 if the message is too long.
+#[no_mangle]
+pub extern "C" fn rust_chacha20poly1305_seal(
+    key: *const u8,
+    nonce: *const u8,
+    aad: *const u8,
+    aad_len: usize,
+    plaintext: *const u8,
+    len: usize,
+    output: *mut u8,
+    tag: *mut u8,
+) -> i32 {
+    aead_seal::<ChaCha20Poly1305>(key, nonce, aad, aad_len, plaintext, len, output, tag)
+}
+
+/// ChaCha20-Poly1305 (RFC 8439) open using chacha20poly1305 crate
+///
+/// `output` receives `len` bytes of plaintext and may alias `ciphertext`.
+/// Returns 0 on success, -1 on tag mismatch.
+#[no_mangle]
+pub extern "C" fn rust_chacha20poly1305_open(
+    key: *const u8,
+    nonce: *const u8,
+    aad: *const u8,
+    aad_len: usize,
+    ciphertext: *const u8,
+    len: usize,
+    tag: *const u8,
+    output: *mut u8,
+) -> i32 {
+    aead_open::<ChaCha20Poly1305>(key, nonce, aad, aad_len, ciphertext, len, tag, output)
+}
+
+/// XChaCha20-Poly1305 seal using chacha20poly1305 crate (32-byte key, 24-byte nonce, 16-byte tag)
+///
+/// `output` receives `len` bytes of ciphertext and may alias `plaintext`.
+/// Returns 0 on success, -1 if the message is too long.
+#[no_mangle]
+pub extern "C" fn rust_xchacha20poly1305_seal(
+    key: *const u8,
+    nonce: *const u8,
+    aad: *const u8,
+    aad_len: usize,
+    plaintext: *const u8,
+    len: usize,
+    output: *mut u8,
+    tag: *mut u8,
+) -> i32 {
+    aead_seal::<XChaCha20Poly1305>(key, nonce, aad, aad_len, plaintext, len, output, tag)
+}
+
+/// XChaCha20-Poly1305 open using chacha20poly1305 crate
+///
+/// `output` receives `len` bytes of plaintext and may alias `ciphertext`.
+/// Returns 0 on success, -1 on tag mismatch.
+#[no_mangle]
+pub extern "C" fn rust_xchacha20poly1305_open(
+    key: *const u8,
+    nonce: *const u8,
+    aad: *const u8,
+    aad_len: usize,
+    ciphertext: *const u8,
+    len: usize,
+    tag: *const u8,
+    output: *mut u8,
+) -> i32 {
+    aead_open::<XChaCha20Poly1305>(key, nonce, aad, aad_len, ciphertext, len, tag, output)
+}
+
+/// AES-128-GCM-SIV (RFC 8452) seal using aes-gcm-siv crate (16-byte key, 12-byte nonce, 16-byte tag)
+///
+/// `output` receives `len` bytes of ciphertext and may alias `plaintext`.
+/// Returns 0 on success, -1 if the message is too long.
+#[no_mangle]
+pub extern "C" fn rust_aes128gcmsiv_seal(
+    key: *const u8,
+    nonce: *const u8,
+    aad: *const u8,
+    aad_len: usize,
+    plaintext: *const u8,
+    len: usize,
+    output: *mut u8,
+    tag: *mut u8,
+) -> i32 {
+    aead_seal::<Aes128GcmSiv>(key, nonce, aad, aad_len, plaintext, len, output, tag)
+}
+
+/// AES-128-GCM-SIV (RFC 8452) open using aes-gcm-siv crate
+///
+/// `output` receives `len` bytes of plaintext and may alias `ciphertext`.
+/// Returns 0 on success, -1 on tag mismatch.
+#[no_mangle]
+pub extern "C" fn rust_aes128gcmsiv_open(
+    key: *const u8,
+    nonce: *const u8,
+    aad: *const u8,
+    aad_len: usize,
+    ciphertext: *const u8,
+    len: usize,
+    tag: *const u8,
+    output: *mut u8,
+) -> i32 {
+    aead_open::<Aes128GcmSiv>(key, nonce, aad, aad_len, ciphertext, len, tag, output)
+}
+
+/// AES-256-GCM-SIV (RFC 8452) seal using aes-gcm-siv crate (32-byte key, 12-byte nonce, 16-byte tag)
+///
+/// `output` receives `len` bytes of ciphertext and may alias `plaintext`.
+/// Returns 0 on success, -1 if the message is too long.
+#[no_mangle]
+pub extern "C" fn rust_aes256gcmsiv_seal(
+    key: *const u8,
+    nonce: *const u8,
+    aad: *const u8,
+    aad_len: usize,
+    plaintext: *const u8,
+    len: usize,
+    output: *mut u8,
+    tag: *mut u8,
+) -> i32 {
+    aead_seal::<Aes256GcmSiv>(key, nonce, aad, aad_len, plaintext, len, output, tag)
+}
+
+/// AES-256-GCM-SIV (RFC 8452) open using aes-gcm-siv crate
+///
+/// `output` receives `len` bytes of plaintext and may alias `ciphertext`.
+/// Returns 0 on success, -1 on tag mismatch.
+#[no_mangle]
+pub extern "C" fn rust_aes256gcmsiv_open(
+    key: *const u8,
+    nonce: *const u8,
+    aad: *const u8,
+    aad_len: usize,
+    ciphertext: *const u8,
+    len: usize,
+    tag: *const u8,
+    output: *mut u8,
+) -> i32 {
+    aead_open::<Aes256GcmSiv>(key, nonce, aad, aad_len, ciphertext, len, tag, output)
+}
+
+/// Collect AES-SIV associated-data components passed as parallel
+/// pointer/length arrays
+fn siv_headers<'a>(
+    aad: *const *const u8,
+    aad_lens: *const usize,
+    aad_count: usize,
+) -> Vec<&'a [u8]> {
+    if aad_count == 0 {
+        return Vec::new();
+    }
+    let ptrs = unsafe { std::slice::from_raw_parts(aad, aad_count) };
+    let lens = unsafe { std::slice::from_raw_parts(aad_lens, aad_count) };
+    ptrs.iter()
+        .zip(lens)
+        .map(|(&ptr, &len)| optional_slice(ptr, len))
+        .collect()
+}
+
+/// AES-SIV (RFC 5297, CMAC-based) seal using aes-siv crate
+///
+/// `key_len` selects AES-128-SIV (32 bytes) or AES-256-SIV (64 bytes). The
+/// `aad_count` associated-data components are given as parallel arrays of
+/// pointers and lengths; a nonce, if used, is passed as the last component.
+/// `output` receives `len` bytes of ciphertext and may alias `plaintext`;
+/// `tag` receives the 16-byte synthetic IV. Returns 0 on success, -1 on an
+/// unsupported key length or too many components.
+#[no_mangle]
+pub extern "C" fn rust_aes_siv_seal(
+    key: *const u8,
+    key_len: usize,
+    aad: *const *const u8,
+    aad_lens: *const usize,
+    aad_count: usize,
+    plaintext: *const u8,
+    len: usize,
+    output: *mut u8,
+    tag: *mut u8,
+) -> i32 {
+    let key = unsafe { std::slice::from_raw_parts(key, key_len) };
+    let headers = siv_headers(aad, aad_lens, aad_count);
+    let buffer = unsafe {
+        std::ptr::copy(plaintext, output, len);
+        std::slice::from_raw_parts_mut(output, len)
+    };
+
+    // Split key into MAC and CTR halves, then compute S2V and encrypt in place
+    let result = match key_len {
+        32 => <Aes128Siv as aes_siv::KeyInit>::new(GenericArray::from_slice(key))
+            .encrypt_in_place_detached(&headers, buffer),
+        64 => <Aes256Siv as aes_siv::KeyInit>::new(GenericArray::from_slice(key))
+            .encrypt_in_place_detached(&headers, buffer),
+        _ => return -1,
+    };
+    let Ok(result) = result else {
+        return -1;
+    };
+
+    // Copy tag to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), tag, 16);
+    }
+    0
+}
+
+/// AES-SIV (RFC 5297, CMAC-based) open using aes-siv crate
+///
+/// Parameters mirror `rust_aes_siv_seal`. `output` receives `len` bytes of
+/// plaintext and may alias `ciphertext`. Returns 0 on success, -1 on tag
+/// mismatch or invalid parameters.
+#[no_mangle]
+pub extern "C" fn rust_aes_siv_open(
+    key: *const u8,
+    key_len: usize,
+    aad: *const *const u8,
+    aad_lens: *const usize,
+    aad_count: usize,
+    ciphertext: *const u8,
+    len: usize,
+    tag: *const u8,
+    output: *mut u8,
+) -> i32 {
+    let key = unsafe { std::slice::from_raw_parts(key, key_len) };
+    let tag = unsafe { std::slice::from_raw_parts(tag, 16) };
+    let headers = siv_headers(aad, aad_lens, aad_count);
+    let buffer = unsafe {
+        std::ptr::copy(ciphertext, output, len);
+        std::slice::from_raw_parts_mut(output, len)
+    };
+
+    // Decrypt in place, then recompute S2V and compare against the tag
+    let result = match key_len {
+        32 => <Aes128Siv as aes_siv::KeyInit>::new(GenericArray::from_slice(key))
+            .decrypt_in_place_detached(&headers, buffer, GenericArray::from_slice(tag)),
+        64 => <Aes256Siv as aes_siv::KeyInit>::new(GenericArray::from_slice(key))
+            .decrypt_in_place_detached(&headers, buffer, GenericArray::from_slice(tag)),
+        _ => return -1,
+    };
+    match result {
+        Ok(()) => 0,
+        Err(_) => -1,
+    }
+}
+
+/// Shared body of the AES-CTR exports: XOR the keystream into `data`
+fn aes_ctr<C>(key: *const u8, iv: *const u8, data: *mut u8, len: usize)
+where
+    C: aes::cipher::BlockCipher
+        + aes::cipher::BlockEncrypt
+        + aes::cipher::BlockSizeUser<BlockSize = aes::cipher::consts::U16>
+        + aes::cipher::KeyInit,
+{
+    let key = unsafe { std::slice::from_raw_parts(key, C::KeySize::USIZE) };
+    let iv = unsafe { std::slice::from_raw_parts(iv, 16) };
+    let buffer = unsafe { std::slice::from_raw_parts_mut(data, len) };
+
+    // Expand key, then generate keystream and XOR in place
+    let mut cipher =
+        ctr::Ctr128BE::<C>::new(GenericArray::from_slice(key), GenericArray::from_slice(iv));
+    cipher.apply_keystream(buffer);
+}
+
+/// AES-128-CTR (128-bit big-endian counter) using ctr crate, in place
+///
+/// 16-byte key and 16-byte initial counter block. Encryption and decryption
+/// are the same keystream XOR.
+#[no_mangle]
+pub extern "C" fn rust_aes128_ctr(key: *const u8, iv: *const u8, data: *mut u8, len: usize) {
+    aes_ctr::<Aes128>(key, iv, data, len)
+}
+
+/// AES-256-CTR (128-bit big-endian counter) using ctr crate, in place
+///
+/// 32-byte key and 16-byte initial counter block. Encryption and decryption
+/// are the same keystream XOR.
+#[no_mangle]
+pub extern "C" fn rust_aes256_ctr(key: *const u8, iv: *const u8, data: *mut u8, len: usize) {
+    aes_ctr::<Aes256>(key, iv, data, len)
+}
+
+/// Shared body of the AES-CBC encrypt exports: PKCS#7 pad and encrypt
+fn aes_cbc_encrypt<C>(
+    key: *const u8,
+    iv: *const u8,
+    buffer: *mut u8,
+    len: usize,
+    capacity: usize,
+) -> isize
+where
+    C: aes::cipher::BlockCipher
+        + aes::cipher::BlockEncryptMut
+        + aes::cipher::BlockSizeUser<BlockSize = aes::cipher::consts::U16>
+        + aes::cipher::KeyInit,
+{
+    let key = unsafe { std::slice::from_raw_parts(key, C::KeySize::USIZE) };
+    let iv = unsafe { std::slice::from_raw_parts(iv, 16) };
+    let buffer = unsafe { std::slice::from_raw_parts_mut(buffer, capacity) };
+
+    // Expand key, then pad and chain-encrypt in place
+    let cipher =
+        cbc::Encryptor::<C>::new(GenericArray::from_slice(key), GenericArray::from_slice(iv));
+    match cipher.encrypt_padded_mut::<Pkcs7>(buffer, len) {
+        Ok(ciphertext) => ciphertext.len() as isize,
+        Err(_) => -1,
+    }
+}
+
+/// Shared body of the AES-CBC decrypt exports: decrypt and strip PKCS#7
+fn aes_cbc_decrypt<C>(key: *const u8, iv: *const u8, buffer: *mut u8, len: usize) -> isize
+where
+    C: aes::cipher::BlockCipher
+        + aes::cipher::BlockDecryptMut
+        + aes::cipher::BlockSizeUser<BlockSize = aes::cipher::consts::U16>
+        + aes::cipher::KeyInit,
+{
+    let key = unsafe { std::slice::from_raw_parts(key, C::KeySize::USIZE) };
+    let iv = unsafe { std::slice::from_raw_parts(iv, 16) };
+    let buffer = unsafe { std::slice::from_raw_parts_mut(buffer, len) };
+
+    // Expand key, then chain-decrypt in place and check the padding
+    let cipher =
+        cbc::Decryptor::<C>::new(GenericArray::from_slice(key), GenericArray::from_slice(iv));
+    match cipher.decrypt_padded_mut::<Pkcs7>(buffer) {
+        Ok(plaintext) => plaintext.len() as isize,
+        Err(_) => -1,
+    }
+}
+
+/// AES-128-CBC encrypt with PKCS#7 padding using cbc crate, in place
+///
+/// `buffer` holds `len` bytes of plaintext and must have room for up to 16
+/// bytes of padding (`capacity`). Returns the ciphertext length, or -1 if
+/// `capacity` is too small.
+#[no_mangle]
+pub extern "C" fn rust_aes128_cbc_encrypt(
+    key: *const u8,
+    iv: *const u8,
+    buffer: *mut u8,
+    len: usize,
+    capacity: usize,
+) -> isize {
+    aes_cbc_encrypt::<Aes128>(key, iv, buffer, len, capacity)
+}
+
+/// AES-128-CBC decrypt with PKCS#7 padding using cbc crate, in place
+///
+/// Returns the plaintext length, or -1 if `len` is not a multiple of 16 or
+/// the padding is invalid.
+#[no_mangle]
+pub extern "C" fn rust_aes128_cbc_decrypt(
+    key: *const u8,
+    iv: *const u8,
+    buffer: *mut u8,
+    len: usize,
+) -> isize {
+    aes_cbc_decrypt::<Aes128>(key, iv, buffer, len)
+}
+
+/// AES-256-CBC encrypt with PKCS#7 padding using cbc crate, in place
+///
+/// Parameters and return value as for `rust_aes128_cbc_encrypt`, with a
+/// 32-byte key.
+#[no_mangle]
+pub extern "C" fn rust_aes256_cbc_encrypt(
+    key: *const u8,
+    iv: *const u8,
+    buffer: *mut u8,
+    len: usize,
+    capacity: usize,
+) -> isize {
+    aes_cbc_encrypt::<Aes256>(key, iv, buffer, len, capacity)
+}
+
+/// AES-256-CBC decrypt with PKCS#7 padding using cbc crate, in place
+///
+/// Parameters and return value as for `rust_aes128_cbc_decrypt`, with a
+/// 32-byte key.
+#[no_mangle]
+pub extern "C" fn rust_aes256_cbc_decrypt(
+    key: *const u8,
+    iv: *const u8,
+    buffer: *mut u8,
+    len: usize,
+) -> isize {
+    aes_cbc_decrypt::<Aes256>(key, iv, buffer, len)
+}
+
+/// Shared body of the AES-XTS exports: IEEE 1619 sectors with
+/// little-endian sector-number tweaks, in place
+fn aes_xts<C>(
+    key: *const u8,
+    data: *mut u8,
+    len: usize,
+    sector_size: usize,
+    first_sector: u64,
+    encrypt: bool,
+) -> i32
+where
+    C: aes::cipher::BlockCipher
+        + aes::cipher::BlockEncrypt
+        + aes::cipher::BlockDecrypt
+        + aes::cipher::KeyInit,
+{
+    // Every sector, including a short final one, needs at least one block
+    let tail = if sector_size == 0 {
+        0
+    } else {
+        len % sector_size
+    };
+    if sector_size < 16 || len < 16 || (tail > 0 && tail < 16) {
+        return -1;
+    }
+    let key_size = C::KeySize::USIZE;
+    let key = unsafe { std::slice::from_raw_parts(key, 2 * key_size) };
+    let buffer = unsafe { std::slice::from_raw_parts_mut(data, len) };
+
+    // Expand the data and tweak keys, then process sector by sector
+    let xts = xts_mode::Xts128::new(
+        C::new(GenericArray::from_slice(&key[..key_size])),
+        C::new(GenericArray::from_slice(&key[key_size..])),
+    );
+    if encrypt {
+        xts.encrypt_area(
+            buffer,
+            sector_size,
+            first_sector.into(),
+            xts_mode::get_tweak_default,
+        );
+    } else {
+        xts.decrypt_area(
+            buffer,
+            sector_size,
+            first_sector.into(),
+            xts_mode::get_tweak_default,
+        );
+    }
+    0
+}
+
+/// AES-128-XTS encrypt using xts-mode crate, in place
+///
+/// 32-byte key (data key then tweak key). `len` bytes are split into
+/// `sector_size`-byte sectors numbered from `first_sector`; a short final
+/// sector uses ciphertext stealing. Returns 0 on success, -1 if any sector
+/// would be shorter than 16 bytes.
+#[no_mangle]
+pub extern "C" fn rust_aes128_xts_encrypt(
+    key: *const u8,
+    data: *mut u8,
+    len: usize,
+    sector_size: usize,
+    first_sector: u64,
+) -> i32 {
+    aes_xts::<Aes128>(key, data, len, sector_size, first_sector, true)
+}
+
+/// AES-128-XTS decrypt using xts-mode crate, in place
+///
+/// Parameters and return value as for `rust_aes128_xts_encrypt`.
+#[no_mangle]
+pub extern "C" fn rust_aes128_xts_decrypt(
+    key: *const u8,
+    data: *mut u8,
+    len: usize,
+    sector_size: usize,
+    first_sector: u64,
+) -> i32 {
+    aes_xts::<Aes128>(key, data, len, sector_size, first_sector, false)
+}
+
+/// AES-256-XTS encrypt using xts-mode crate, in place
+///
+/// 64-byte key (data key then tweak key); otherwise as for
+/// `rust_aes128_xts_encrypt`.
+#[no_mangle]
+pub extern "C" fn rust_aes256_xts_encrypt(
+    key: *const u8,
+    data: *mut u8,
+    len: usize,
+    sector_size: usize,
+    first_sector: u64,
+) -> i32 {
+    aes_xts::<Aes256>(key, data, len, sector_size, first_sector, true)
+}
+
+/// AES-256-XTS decrypt using xts-mode crate, in place
+///
+/// Parameters and return value as for `rust_aes256_xts_encrypt`.
+#[no_mangle]
+pub extern "C" fn rust_aes256_xts_decrypt(
+    key: *const u8,
+    data: *mut u8,
+    len: usize,
+    sector_size: usize,
+    first_sector: u64,
+) -> i32 {
+    aes_xts::<Aes256>(key, data, len, sector_size, first_sector, false)
+}
+
+/// Streaming wrapper for a 16-byte-block universal hash
+///
+/// `UniversalHash::update` only takes whole blocks, so partial blocks are
+/// buffered here until the next update or finalization. The keyed initial
+/// state is kept so finalization can reset the context for reuse.
+pub struct UniversalHashStream<U> {
+    hash: U,
+    initial: U,
+    buffer: [u8; 16],
+    buffered: usize,
+}
+
+impl<U: UniversalHash<BlockSize = poly1305::universal_hash::consts::U16> + Clone>
+    UniversalHashStream<U>
+{
+    fn new(hash: U) -> Self {
+        Self {
+            initial: hash.clone(),
+            hash,
+            buffer: [0; 16],
+            buffered: 0,
+        }
+    }
+
+    /// Hand the absorbed state and trailing partial block to `finish`, then
+    /// return the context to its keyed initial state
+    fn finish_reset<R>(&mut self, finish: impl FnOnce(U, &[u8]) -> R) -> R {
+        let hash = std::mem::replace(&mut self.hash, self.initial.clone());
+        let buffered = std::mem::take(&mut self.buffered);
+        finish(hash, &self.buffer[..buffered])
+    }
+
+    fn absorb(&mut self, mut data: &[u8]) {
+        // Top up a previously buffered partial block first
+        if self.buffered > 0 {
+            let take = data.len().min(16 - self.buffered);
+            self.buffer[self.buffered..self.buffered + take].copy_from_slice(&data[..take]);
+            self.buffered += take;
+            data = &data[take..];
+            if self.buffered < 16 {
+                return;
+            }
+            self.hash.update(&[self.buffer.into()]);
+            self.buffered = 0;
+        }
+
+        // Process whole blocks directly (no padding applies), buffer the rest
+        let (blocks, rest) = data.split_at(data.len() - data.len() % 16);
+        self.hash.update_padded(blocks);
+        self.buffer[..rest.len()].copy_from_slice(rest);
+        self.buffered = rest.len();
+    }
+}
+
+/// Poly1305 using poly1305 crate (32-byte key, 16-byte tag)
+///
+/// The final partial block uses Poly1305's own 0x01 padding (RFC 8439),
+/// so the tag matches the unpadded one-time authenticator.
+#[no_mangle]
+pub extern "C" fn rust_poly1305(key: *const u8, data: *const u8, len: usize, tag: *mut u8) {
+    let key = unsafe { std::slice::from_raw_parts(key, 32) };
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Load key, then process data
+    let hash = <Poly1305 as poly1305::universal_hash::KeyInit>::new(GenericArray::from_slice(key));
+    let result = hash.compute_unpadded(input);
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), tag, 16);
+    }
+}
+
+/// Allocate a streaming Poly1305 context; release it with `rust_poly1305_free`
+#[no_mangle]
+pub extern "C" fn rust_poly1305_new(key: *const u8) -> *mut UniversalHashStream<Poly1305> {
+    let key = unsafe { std::slice::from_raw_parts(key, 32) };
+    let hash = <Poly1305 as poly1305::universal_hash::KeyInit>::new(GenericArray::from_slice(key));
+    Box::into_raw(Box::new(UniversalHashStream::new(hash)))
+}
+
+/// Absorb `len` bytes into a streaming Poly1305 context (any chunking)
+#[no_mangle]
+pub extern "C" fn rust_poly1305_update(
+    ctx: *mut UniversalHashStream<Poly1305>,
+    data: *const u8,
+    len: usize,
+) {
+    let stream = unsafe { &mut *ctx };
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+    stream.absorb(input);
+}
+
+/// Write the Poly1305 tag of everything absorbed so far, then reset the
+/// context so it can be reused for the next message under the same key
+///
+/// Poly1305 keys are one-time; reuse is only meant for benchmark loops.
+#[no_mangle]
+pub extern "C" fn rust_poly1305_finalize(ctx: *mut UniversalHashStream<Poly1305>, tag: *mut u8) {
+    let stream = unsafe { &mut *ctx };
+    let result = stream.finish_reset(|hash, rest| hash.compute_unpadded(rest));
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), tag, 16);
+    }
+}
+
+/// Free a context returned by `rust_poly1305_new` (NULL is ignored)
+#[no_mangle]
+pub extern "C" fn rust_poly1305_free(ctx: *mut UniversalHashStream<Poly1305>) {
+    if !ctx.is_null() {
+        drop(unsafe { Box::from_raw(ctx) });
+    }
+}
+
+/// GHASH using ghash crate (16-byte key, 16-byte tag)
+///
+/// A final partial block is zero-padded, as GCM does for AAD and ciphertext.
+#[no_mangle]
+pub extern "C" fn rust_ghash(key: *const u8, data: *const u8, len: usize, tag: *mut u8) {
+    let key = unsafe { std::slice::from_raw_parts(key, 16) };
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Load key, then process data
+    let mut hash = <GHash as poly1305::universal_hash::KeyInit>::new(GenericArray::from_slice(key));
+    hash.update_padded(input);
+    let result = hash.finalize();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), tag, 16);
+    }
+}
+
+/// Allocate a streaming GHASH context; release it with `rust_ghash_free`
+#[no_mangle]
+pub extern "C" fn rust_ghash_new(key: *const u8) -> *mut UniversalHashStream<GHash> {
+    let key = unsafe { std::slice::from_raw_parts(key, 16) };
+    let hash = <GHash as poly1305::universal_hash::KeyInit>::new(GenericArray::from_slice(key));
+    Box::into_raw(Box::new(UniversalHashStream::new(hash)))
+}
+
+/// Absorb `len` bytes into a streaming GHASH context (any chunking)
+#[no_mangle]
+pub extern "C" fn rust_ghash_update(
+    ctx: *mut UniversalHashStream<GHash>,
+    data: *const u8,
+    len: usize,
+) {
+    let stream = unsafe { &mut *ctx };
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+    stream.absorb(input);
+}
+
+/// Write the GHASH tag of everything absorbed so far, then reset the
+/// context so it can be reused for the next message under the same key
+#[no_mangle]
+pub extern "C" fn rust_ghash_finalize(ctx: *mut UniversalHashStream<GHash>, tag: *mut u8) {
+    let stream = unsafe { &mut *ctx };
+    let result = stream.finish_reset(|mut hash, rest| {
+        hash.update_padded(rest);
+        hash.finalize()
+    });
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), tag, 16);
+    }
+}
+
+/// Free a context returned by `rust_ghash_new` (NULL is ignored)
+#[no_mangle]
+pub extern "C" fn rust_ghash_free(ctx: *mut UniversalHashStream<GHash>) {
+    if !ctx.is_null() {
+        drop(unsafe { Box::from_raw(ctx) });
+    }
+}
+
+/// POLYVAL using polyval crate (16-byte key, 16-byte tag)
+///
+/// A final partial block is zero-padded, as AES-GCM-SIV does for AAD and
+/// plaintext.
+#[no_mangle]
+pub extern "C" fn rust_polyval(key: *const u8, data: *const u8, len: usize, tag: *mut u8) {
+    let key = unsafe { std::slice::from_raw_parts(key, 16) };
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Load key, then process data
+    let mut hash =
+        <Polyval as poly1305::universal_hash::KeyInit>::new(GenericArray::from_slice(key));
+    hash.update_padded(input);
+    let result = hash.finalize();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), tag, 16);
+    }
+}
+
+/// Allocate a streaming POLYVAL context; release it with `rust_polyval_free`
+#[no_mangle]
+pub extern "C" fn rust_polyval_new(key: *const u8) -> *mut UniversalHashStream<Polyval> {
+    let key = unsafe { std::slice::from_raw_parts(key, 16) };
+    let hash = <Polyval as poly1305::universal_hash::KeyInit>::new(GenericArray::from_slice(key));
+    Box::into_raw(Box::new(UniversalHashStream::new(hash)))
+}
+
+/// Absorb `len` bytes into a streaming POLYVAL context (any chunking)
+#[no_mangle]
+pub extern "C" fn rust_polyval_update(
+    ctx: *mut UniversalHashStream<Polyval>,
+    data: *const u8,
+    len: usize,
+) {
+    let stream = unsafe { &mut *ctx };
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+    stream.absorb(input);
+}
+
+/// Write the POLYVAL tag of everything absorbed so far, then reset the
+/// context so it can be reused for the next message under the same key
+#[no_mangle]
+pub extern "C" fn rust_polyval_finalize(ctx: *mut UniversalHashStream<Polyval>, tag: *mut u8) {
+    let stream = unsafe { &mut *ctx };
+    let result = stream.finish_reset(|mut hash, rest| {
+        hash.update_padded(rest);
+        hash.finalize()
+    });
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), tag, 16);
+    }
+}
+
+/// Free a context returned by `rust_polyval_new` (NULL is ignored)
+#[no_mangle]
+pub extern "C" fn rust_polyval_free(ctx: *mut UniversalHashStream<Polyval>) {
+    if !ctx.is_null() {
+        drop(unsafe { Box::from_raw(ctx) });
+    }
+}
+
+/// AES-128-CMAC (NIST SP 800-38B) using cmac crate (16-byte key, 16-byte tag)
+#[no_mangle]
+pub extern "C" fn rust_aes128_cmac(key: *const u8, data: *const u8, len: usize, tag: *mut u8) {
+    let key = unsafe { std::slice::from_raw_parts(key, 16) };
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Expand key and derive subkeys, then process data
+    let mut mac = <Cmac<Aes128> as Mac>::new_from_slice(key).expect("key is 16 bytes");
+    mac.update(input);
+    let result = mac.finalize().into_bytes();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), tag, 16);
+    }
+}
+
+/// AES-256-CMAC (NIST SP 800-38B) using cmac crate (32-byte key, 16-byte tag)
+#[no_mangle]
+pub extern "C" fn rust_aes256_cmac(key: *const u8, data: *const u8, len: usize, tag: *mut u8) {
+    let key = unsafe { std::slice::from_raw_parts(key, 32) };
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+
+    // Expand key and derive subkeys, then process data
+    let mut mac = <Cmac<Aes256> as Mac>::new_from_slice(key).expect("key is 32 bytes");
+    mac.update(input);
+    let result = mac.finalize().into_bytes();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), tag, 16);
+    }
+}
+
+/// KMAC128 (NIST SP 800-185) using tiny-keccak crate, writing `out_len` bytes
+///
+/// The customization string may be empty (NULL, 0). The requested output
+/// length is bound into the MAC, so different lengths give unrelated tags.
+#[no_mangle]
+pub extern "C" fn rust_kmac128(
+    key: *const u8,
+    key_len: usize,
+    custom: *const u8,
+    custom_len: usize,
+    data: *const u8,
+    len: usize,
+    output: *mut u8,
+    out_len: usize,
+) {
+    let key = optional_slice(key, key_len);
+    let custom = optional_slice(custom, custom_len);
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+    let out = unsafe { std::slice::from_raw_parts_mut(output, out_len) };
+
+    // Absorb the encoded key block, then data, then squeeze into the output
+    let mut kmac = Kmac::v128(key, custom);
+    kmac.update(input);
+    kmac.finalize(out);
+}
+
+/// KMAC256 (NIST SP 800-185) using tiny-keccak crate, writing `out_len` bytes
+///
+/// The customization string may be empty (NULL, 0). The requested output
+/// length is bound into the MAC, so different lengths give unrelated tags.
+#[no_mangle]
+pub extern "C" fn rust_kmac256(
+    key: *const u8,
+    key_len: usize,
+    custom: *const u8,
+    custom_len: usize,
+    data: *const u8,
+    len: usize,
+    output: *mut u8,
+    out_len: usize,
+) {
+    let key = optional_slice(key, key_len);
+    let custom = optional_slice(custom, custom_len);
+    let input = unsafe { std::slice::from_raw_parts(data, len) };
+    let out = unsafe { std::slice::from_raw_parts_mut(output, out_len) };
+
+    // Absorb the encoded key block, then data, then squeeze into the output
+    let mut kmac = Kmac::v256(key, custom);
+    kmac.update(input);
+    kmac.finalize(out);
+}
+
+/// Derive the 32-byte Ed25519 public key from a 32-byte seed using ed25519-dalek
+#[no_mangle]
+pub extern "C" fn rust_ed25519_keypair_from_seed(seed: *const u8, public_key: *mut u8) {
+    let seed = unsafe { &*(seed as *const [u8; 32]) };
+
+    // Hash and clamp the seed, then multiply the basepoint
+    let signing_key = SigningKey::from_bytes(seed);
+    let result = signing_key.verifying_key().to_bytes();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), public_key, 32);
+    }
+}
+
+/// Ed25519 sign from a 32-byte seed using ed25519-dalek, writing a 64-byte signature
+///
+/// This is the full-cost path: the seed is re-expanded and the public key
+/// re-derived on every call. See `rust_ed25519_expanded_new` for the
+/// precomputed alternative.
+#[no_mangle]
+pub extern "C" fn rust_ed25519_sign(
+    seed: *const u8,
+    message: *const u8,
+    len: usize,
+    signature: *mut u8,
+) {
+    let seed = unsafe { &*(seed as *const [u8; 32]) };
+    let message = optional_slice(message, len);
+
+    // Expand the seed, then sign deterministically
+    let signing_key = SigningKey::from_bytes(seed);
+    let result = signing_key.sign(message).to_bytes();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), signature, 64);
+    }
+}
+
+/// Expanded Ed25519 secret key (clamped scalar and nonce prefix) together
+/// with its public key, so signing skips the seed hash and basepoint multiply
+pub struct Ed25519ExpandedKey {
+    secret: ExpandedSecretKey,
+    public: VerifyingKey,
+}
+
+/// Expand a 32-byte Ed25519 seed once; release it with `rust_ed25519_expanded_free`
+#[no_mangle]
+pub extern "C" fn rust_ed25519_expanded_new(seed: *const u8) -> *mut Ed25519ExpandedKey {
+    let seed = unsafe { &*(seed as *const [u8; 32]) };
+    let signing_key = SigningKey::from_bytes(seed);
+    let key = Ed25519ExpandedKey {
+        secret: ExpandedSecretKey::from(seed),
+        public: signing_key.verifying_key(),
+    };
+    Box::into_raw(Box::new(key))
+}
+
+/// Ed25519 sign with a pre-expanded secret key, writing a 64-byte signature
+///
+/// Produces the same signature as `rust_ed25519_sign` for the same seed.
+#[no_mangle]
+pub extern "C" fn rust_ed25519_sign_expanded(
+    key: *const Ed25519ExpandedKey,
+    message: *const u8,
+    len: usize,
+    signature: *mut u8,
+) {
+    let key = unsafe { &*key };
+    let message = optional_slice(message, len);
+
+    // Sign deterministically with the cached scalar and public key
+    let result = ed25519_dalek::hazmat::raw_sign::<Sha512>(&key.secret, message, &key.public);
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.to_bytes().as_ptr(), signature, 64);
+    }
+}
+
+/// Free a key returned by `rust_ed25519_expanded_new` (NULL is ignored)
+#[no_mangle]
+pub extern "C" fn rust_ed25519_expanded_free(key: *mut Ed25519ExpandedKey) {
+    if !key.is_null() {
+        drop(unsafe { Box::from_raw(key) });
+    }
+}
+
+/// Ed25519 verify using ed25519-dalek's strict check
+///
+/// Like Zig's verifier, this rejects non-canonical signatures and
+/// small-order public keys and R values. Returns 0 if the signature is
+/// valid, -1 otherwise (including an undecodable public key).
+#[no_mangle]
+pub extern "C" fn rust_ed25519_verify(
+    public_key: *const u8,
+    message: *const u8,
+    len: usize,
+    signature: *const u8,
+) -> i32 {
+    let public_key = unsafe { &*(public_key as *const [u8; 32]) };
+    let message = optional_slice(message, len);
+    let signature = unsafe { &*(signature as *const [u8; 64]) };
+
+    // Decompress the public key, then check the verification equation
+    let Ok(verifying_key) = VerifyingKey::from_bytes(public_key) else {
+        return -1;
+    };
+    match verifying_key.verify_strict(message, &Signature::from_bytes(signature)) {
+        Ok(()) => 0,
+        Err(_) => -1,
+    }
+}
+
+/// Ed25519 batch verification using ed25519-dalek's `verify_batch`
+///
+/// Messages are passed as parallel arrays of pointers and lengths; public
+/// keys (32 bytes each) and signatures (64 bytes each) are packed
+/// contiguously, `count` of each. Weak (small-order) public keys are rejected
+/// as `rust_ed25519_verify` rejects them; the batch equation itself is
+/// dalek's cofactored one, which unlike the strict single check accepts a
+/// small-order R. Returns 0 if every signature is valid. Otherwise returns -1
+/// and, unless `failed_index` is NULL, writes the index of the first entry
+/// that fails the same check on its own (or `count` if none does alone).
+#[no_mangle]
+pub extern "C" fn rust_ed25519_verify_batch(
+    messages: *const *const u8,
+    message_lens: *const usize,
+    public_keys: *const u8,
+    signatures: *const u8,
+    count: usize,
+    failed_index: *mut usize,
+) -> i32 {
+    if count == 0 {
+        return 0;
+    }
+    let message_ptrs = unsafe { std::slice::from_raw_parts(messages, count) };
+    let message_lens = unsafe { std::slice::from_raw_parts(message_lens, count) };
+    let public_keys = unsafe { std::slice::from_raw_parts(public_keys, count * 32) };
+    let signatures = unsafe { std::slice::from_raw_parts(signatures, count * 64) };
+    let report = |index: usize| {
+        if !failed_index.is_null() {
+            unsafe {
+                *failed_index = index;
+            }
+        }
+        -1
+    };
+
+    // Decode inputs; an undecodable or weak public key fails at its own index
+    let messages: Vec<&[u8]> = message_ptrs
+        .iter()
+        .zip(message_lens)
+        .map(|(&ptr, &len)| optional_slice(ptr, len))
+        .collect();
+    let signatures: Vec<Signature> = signatures
+        .chunks_exact(64)
+        .map(|bytes| Signature::from_slice(bytes).expect("chunk is 64 bytes"))
+        .collect();
+    let mut verifying_keys = Vec::with_capacity(count);
+    for (index, bytes) in public_keys.chunks_exact(32).enumerate() {
+        let bytes = bytes.try_into().expect("chunk is 32 bytes");
+        let Ok(key) = VerifyingKey::from_bytes(bytes) else {
+            return report(index);
+        };
+        if key.is_weak() {
+            return report(index);
+        }
+        verifying_keys.push(key);
+    }
+
+    // Check the whole batch with one multiscalar multiplication
+    if ed25519_dalek::verify_batch(&messages, &signatures, &verifying_keys).is_ok() {
+        return 0;
+    }
+
+    // Rejected: find the culprit with one-entry batches (not on the fast path)
+    let index = (0..count)
+        .find(|&i| {
+            let range = i..i + 1;
+            ed25519_dalek::verify_batch(
+                &messages[range.clone()],
+                &signatures[range.clone()],
+                &verifying_keys[range],
+            )
+            .is_err()
+        })
+        .unwrap_or(count);
+    report(index)
+}
+
+/// X25519 public key from a 32-byte secret using x25519-dalek (basepoint scalar multiply)
+#[no_mangle]
+pub extern "C" fn rust_x25519_public_key(secret: *const u8, public_key: *mut u8) {
+    let secret = unsafe { *(secret as *const [u8; 32]) };
+
+    // Clamp the scalar, then multiply the basepoint
+    let result = PublicKey::from(&StaticSecret::from(secret));
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_bytes().as_ptr(), public_key, 32);
+    }
+}
+
+/// X25519 shared secret using x25519-dalek (variable-base scalar multiply)
+///
+/// Returns 0 on success, or -1 if the peer's public key is a low-order point
+/// and the result is all zeros (the same case Zig reports as
+/// `IdentityElement`). The all-zero output is still written on rejection.
+#[no_mangle]
+pub extern "C" fn rust_x25519_shared(
+    secret: *const u8,
+    peer_public: *const u8,
+    shared: *mut u8,
+) -> i32 {
+    let secret = unsafe { *(secret as *const [u8; 32]) };
+    let peer_public = unsafe { *(peer_public as *const [u8; 32]) };
+
+    // Clamp the scalar, then run the Montgomery ladder on the peer's point
+    let result = StaticSecret::from(secret).diffie_hellman(&PublicKey::from(peer_public));
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_bytes().as_ptr(), shared, 32);
+    }
+    if result.was_contributory() {
+        0
+    } else {
+        -1
+    }
+}
+
+/// secp256k1 public key from a 32-byte secret using k256, as 65-byte uncompressed SEC1
+///
+/// Returns 0 on success, -1 if the secret is zero or not below the group order.
+#[no_mangle]
+pub extern "C" fn rust_secp256k1_public_key(secret: *const u8, public_key: *mut u8) -> i32 {
+    let secret = unsafe { std::slice::from_raw_parts(secret, 32) };
+
+    // Multiply the generator by the secret scalar
+    let Ok(signing_key) = k256::ecdsa::SigningKey::from_slice(secret) else {
+        return -1;
+    };
+    let result = signing_key.verifying_key().to_encoded_point(false);
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_bytes().as_ptr(), public_key, 65);
+    }
+    0
+}
+
+/// Shared tail of the secp256k1 signing exports: write r || s || v
+fn secp256k1_write_recoverable(
+    signed: k256::ecdsa::Signature,
+    recovery_id: k256::ecdsa::RecoveryId,
+    signature: *mut u8,
+) {
+    let result = signed.to_bytes();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), signature, 64);
+        *signature.add(64) = recovery_id.to_byte();
+    }
+}
+
+/// secp256k1 ECDSA sign over SHA-256(message) using k256 (RFC 6979 nonces)
+///
+/// Writes a 65-byte recoverable signature r || s || v with low-S
+/// normalization and v in {0, 1}. Returns 0 on success, -1 if the secret is
+/// invalid.
+#[no_mangle]
+pub extern "C" fn rust_secp256k1_sign(
+    secret: *const u8,
+    message: *const u8,
+    len: usize,
+    signature: *mut u8,
+) -> i32 {
+    let secret = unsafe { std::slice::from_raw_parts(secret, 32) };
+    let message = optional_slice(message, len);
+
+    // Hash the message, derive the nonce deterministically and sign
+    let Ok(signing_key) = k256::ecdsa::SigningKey::from_slice(secret) else {
+        return -1;
+    };
+    let Ok((signed, recovery_id)) = signing_key.sign_recoverable(message) else {
+        return -1;
+    };
+    secp256k1_write_recoverable(signed, recovery_id, signature);
+    0
+}
+
+/// secp256k1 ECDSA sign over a caller-supplied 32-byte hash using k256
+///
+/// For Ethereum-style flows where the message is hashed with Keccak-256
+/// first. Output and return value as for `rust_secp256k1_sign`.
+#[no_mangle]
+pub extern "C" fn rust_secp256k1_sign_prehash(
+    secret: *const u8,
+    prehash: *const u8,
+    signature: *mut u8,
+) -> i32 {
+    let secret = unsafe { std::slice::from_raw_parts(secret, 32) };
+    let prehash = unsafe { std::slice::from_raw_parts(prehash, 32) };
+
+    // Derive the nonce deterministically and sign
+    let Ok(signing_key) = k256::ecdsa::SigningKey::from_slice(secret) else {
+        return -1;
+    };
+    let Ok((signed, recovery_id)) = signing_key.sign_prehash_recoverable(prehash) else {
+        return -1;
+    };
+    secp256k1_write_recoverable(signed, recovery_id, signature);
+    0
+}
+
+/// secp256k1 ECDSA verify over SHA-256(message) using k256
+///
+/// The public key is SEC1-encoded, compressed (33 bytes) or uncompressed
+/// (65 bytes); the signature is r || s (64 bytes). k256 only accepts low-S
+/// signatures. Returns 0 if valid, -1 otherwise.
+#[no_mangle]
+pub extern "C" fn rust_secp256k1_verify(
+    public_key: *const u8,
+    public_key_len: usize,
+    message: *const u8,
+    len: usize,
+    signature: *const u8,
+) -> i32 {
+    let public_key = unsafe { std::slice::from_raw_parts(public_key, public_key_len) };
+    let message = optional_slice(message, len);
+    let signature = unsafe { std::slice::from_raw_parts(signature, 64) };
+
+    // Decode (and decompress) the key, then hash and verify
+    let Ok(verifying_key) = k256::ecdsa::VerifyingKey::from_sec1_bytes(public_key) else {
+        return -1;
+    };
+    let Ok(signature) = k256::ecdsa::Signature::from_slice(signature) else {
+        return -1;
+    };
+    match verifying_key.verify(message, &signature) {
+        Ok(()) => 0,
+        Err(_) => -1,
+    }
+}
+
+/// secp256k1 public key recovery (`ecrecover`) from a 32-byte hash using k256
+///
+/// Takes a 65-byte r || s || v signature (v in {0, 1}; 27/28 are also
+/// accepted) and writes the 65-byte uncompressed SEC1 public key. As with
+/// Ethereum's `ecrecover`, high-S signatures are accepted: (r, n - s, v ^ 1)
+/// recovers the same key as (r, s, v). The key is computed directly as
+/// r^-1 (sR - zG) with no follow-up verification, which k256's
+/// `recover_from_prehash` would add (and which would reject high S). Returns
+/// 0 on success, -1 if no key can be recovered.
+#[no_mangle]
+pub extern "C" fn rust_secp256k1_recover(
+    prehash: *const u8,
+    signature: *const u8,
+    public_key: *mut u8,
+) -> i32 {
+    let prehash = unsafe { std::slice::from_raw_parts(prehash, 32) };
+    let signature = unsafe { std::slice::from_raw_parts(signature, 65) };
+
+    // Decode r, s and the y parity of R
+    let Ok(signed) = k256::ecdsa::Signature::from_slice(&signature[..64]) else {
+        return -1;
+    };
+    let y_odd = match signature[64] {
+        0 | 27 => 0,
+        1 | 28 => 1,
+        _ => return -1,
+    };
+    let (r, s) = signed.split_scalars();
+    let z =
+        <k256::Scalar as Reduce<k256::U256>>::reduce_bytes(k256::FieldBytes::from_slice(prehash));
+
+    // Reconstruct R from r and v, then solve Q = r^-1 (sR - zG)
+    let big_r = k256::AffinePoint::decompress(&r.to_bytes(), y_odd.into());
+    let Some(big_r) = Option::<k256::AffinePoint>::from(big_r) else {
+        return -1;
+    };
+    let r_inv = *r.invert();
+    let recovered = k256::ProjectivePoint::lincomb(
+        &k256::ProjectivePoint::GENERATOR,
+        &-(r_inv * z),
+        &big_r.into(),
+        &(r_inv * *s),
+    );
+    let Ok(recovered) = k256::ecdsa::VerifyingKey::from_affine(recovered.to_affine()) else {
+        return -1;
+    };
+    let result = recovered.to_encoded_point(false);
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_bytes().as_ptr(), public_key, 65);
+    }
+    0
+}
+
+/// BIP-340 x-only public key (32 bytes) from a 32-byte secret using k256
+///
+/// Returns 0 on success, -1 if the secret is zero or not below the group order.
+#[no_mangle]
+pub extern "C" fn rust_schnorr_bip340_public_key(secret: *const u8, public_key: *mut u8) -> i32 {
+    let secret = unsafe { std::slice::from_raw_parts(secret, 32) };
+
+    // Multiply the generator, keeping only the x-coordinate
+    let Ok(signing_key) = k256::schnorr::SigningKey::from_bytes(secret) else {
+        return -1;
+    };
+    let result = signing_key.verifying_key().to_bytes();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), public_key, 32);
+    }
+    0
+}
+
+/// BIP-340 Schnorr sign using k256, writing a 64-byte signature
+///
+/// The message is signed as-is (no pre-hashing), as in the BIP-340 test
+/// vectors, with the caller's 32 bytes of auxiliary randomness. Returns 0 on
+/// success, -1 if the secret is invalid.
+#[no_mangle]
+pub extern "C" fn rust_schnorr_bip340_sign(
+    secret: *const u8,
+    message: *const u8,
+    len: usize,
+    aux_rand: *const u8,
+    signature: *mut u8,
+) -> i32 {
+    let secret = unsafe { std::slice::from_raw_parts(secret, 32) };
+    let message = optional_slice(message, len);
+    let aux_rand = unsafe { &*(aux_rand as *const [u8; 32]) };
+
+    // Derive the nonce from the masked secret and message, then sign
+    let Ok(signing_key) = k256::schnorr::SigningKey::from_bytes(secret) else {
+        return -1;
+    };
+    let Ok(signed) = signing_key.sign_raw(message, aux_rand) else {
+        return -1;
+    };
+    let result = signed.to_bytes();
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), signature, 64);
+    }
+    0
+}
+
+/// BIP-340 Schnorr verify using k256
+///
+/// Takes a 32-byte x-only public key and a 64-byte signature over the raw
+/// message. Returns 0 if valid, -1 otherwise (including a public key that
+/// is not on the curve or r/s out of range).
+#[no_mangle]
+pub extern "C" fn rust_schnorr_bip340_verify(
+    public_key: *const u8,
+    message: *const u8,
+    len: usize,
+    signature: *const u8,
+) -> i32 {
+    let public_key = unsafe { std::slice::from_raw_parts(public_key, 32) };
+    let message = optional_slice(message, len);
+    let signature = unsafe { std::slice::from_raw_parts(signature, 64) };
+
+    // Lift x to the even-y point, then check s*G - e*P == R
+    let Ok(verifying_key) = k256::schnorr::VerifyingKey::from_bytes(public_key) else {
+        return -1;
+    };
+    let Ok(signature) = k256::schnorr::Signature::try_from(signature) else {
+        return -1;
+    };
+    match verifying_key.verify_raw(message, &signature) {
+        Ok(()) => 0,
+        Err(_) => -1,
+    }
+}
+
+/// Generate the public-key, ECDSA and ECDH exports for a NIST curve crate
+///
+/// Public keys are SEC1-encoded; every entry point that takes one accepts
+/// both compressed and uncompressed forms, so decompression cost shows up
+/// in the measurement when compressed keys are used.
+macro_rules! nist_curve_exports {
+    (
+        $curve:ident, $name:literal, $digest:literal,
+        $scalar_len:literal, $compressed_len:literal, $uncompressed_len:literal,
+        $public_key:ident, $sign:ident, $verify:ident, $ecdh:ident
+    ) => {
+        #[doc = concat!(
+                    $name, " public key from a ", stringify!($scalar_len),
+                    "-byte secret using ", stringify!($curve), ", SEC1-encoded\n\n",
+                    "Writes ", stringify!($compressed_len), " bytes if `compressed` is set, ",
+                    "otherwise ", stringify!($uncompressed_len), ". Returns the number of ",
+                    "bytes written, or -1 if the secret is zero or not below the group order."
+                )]
+        #[no_mangle]
+        pub extern "C" fn $public_key(
+            secret: *const u8,
+            compressed: bool,
+            public_key: *mut u8,
+        ) -> isize {
+            let secret = unsafe { std::slice::from_raw_parts(secret, $scalar_len) };
+
+            // Multiply the generator by the secret scalar, then encode
+            let Ok(signing_key) = $curve::ecdsa::SigningKey::from_slice(secret) else {
+                return -1;
+            };
+            let result = signing_key.verifying_key().to_encoded_point(compressed);
+
+            // Copy result to output buffer
+            unsafe {
+                std::ptr::copy_nonoverlapping(result.as_bytes().as_ptr(), public_key, result.len());
+            }
+            result.len() as isize
+        }
+
+        #[doc = concat!(
+                    $name, " ECDSA sign over ", $digest, "(message) using ", stringify!($curve),
+                    " (RFC 6979 nonces)\n\n",
+                    "Writes a ", stringify!($scalar_len), " + ", stringify!($scalar_len),
+                    "-byte r || s signature. Returns 0 on success, -1 if the secret is invalid."
+                )]
+        #[no_mangle]
+        pub extern "C" fn $sign(
+            secret: *const u8,
+            message: *const u8,
+            len: usize,
+            signature: *mut u8,
+        ) -> i32 {
+            let secret = unsafe { std::slice::from_raw_parts(secret, $scalar_len) };
+            let message = optional_slice(message, len);
+
+            // Hash the message, derive the nonce deterministically and sign
+            let Ok(signing_key) = $curve::ecdsa::SigningKey::from_slice(secret) else {
+                return -1;
+            };
+            let signed: $curve::ecdsa::Signature = signing_key.sign(message);
+            let result = signed.to_bytes();
+
+            // Copy result to output buffer
+            unsafe {
+                std::ptr::copy_nonoverlapping(result.as_ptr(), signature, 2 * $scalar_len);
+            }
+            0
+        }
+
+        #[doc = concat!(
+                    $name, " ECDSA verify over ", $digest, "(message) using ", stringify!($curve),
+                    "\n\n",
+                    "Takes a SEC1 public key (compressed or uncompressed) and an r || s ",
+                    "signature. Returns 0 if valid, -1 otherwise."
+                )]
+        #[no_mangle]
+        pub extern "C" fn $verify(
+            public_key: *const u8,
+            public_key_len: usize,
+            message: *const u8,
+            len: usize,
+            signature: *const u8,
+        ) -> i32 {
+            let public_key = unsafe { std::slice::from_raw_parts(public_key, public_key_len) };
+            let message = optional_slice(message, len);
+            let signature = unsafe { std::slice::from_raw_parts(signature, 2 * $scalar_len) };
+
+            // Decode (and decompress) the key, then hash and verify
+            let Ok(verifying_key) = $curve::ecdsa::VerifyingKey::from_sec1_bytes(public_key) else {
+                return -1;
+            };
+            let Ok(signature) = $curve::ecdsa::Signature::from_slice(signature) else {
+                return -1;
+            };
+            match verifying_key.verify(message, &signature) {
+                Ok(()) => 0,
+                Err(_) => -1,
+            }
+        }
+
+        #[doc = concat!(
+                    $name, " ECDH using ", stringify!($curve), ", writing the ",
+                    stringify!($scalar_len), "-byte x-coordinate shared secret\n\n",
+                    "Takes the peer's SEC1 public key (compressed or uncompressed). Returns 0 ",
+                    "on success, -1 if the secret or the peer key is invalid."
+                )]
+        #[no_mangle]
+        pub extern "C" fn $ecdh(
+            secret: *const u8,
+            peer_public: *const u8,
+            peer_public_len: usize,
+            shared: *mut u8,
+        ) -> i32 {
+            let secret = unsafe { std::slice::from_raw_parts(secret, $scalar_len) };
+            let peer_public = unsafe { std::slice::from_raw_parts(peer_public, peer_public_len) };
+
+            // Decode the scalar and (decompress) the peer point
+            let Ok(secret) = $curve::SecretKey::from_slice(secret) else {
+                return -1;
+            };
+            let Ok(peer_public) = $curve::PublicKey::from_sec1_bytes(peer_public) else {
+                return -1;
+            };
+
+            // Variable-base scalar multiplication
+            let result =
+                $curve::ecdh::diffie_hellman(secret.to_nonzero_scalar(), peer_public.as_affine());
+
+            // Copy result to output buffer
+            unsafe {
+                std::ptr::copy_nonoverlapping(
+                    result.raw_secret_bytes().as_ptr(),
+                    shared,
+                    $scalar_len,
+                );
+            }
+            0
+        }
+    };
+}
+
+nist_curve_exports!(
+    p256,
+    "P-256",
+    "SHA-256",
+    32,
+    33,
+    65,
+    rust_p256_public_key,
+    rust_p256_sign,
+    rust_p256_verify,
+    rust_p256_ecdh
+);
+
+nist_curve_exports!(
+    p384,
+    "P-384",
+    "SHA-384",
+    48,
+    49,
+    97,
+    rust_p384_public_key,
+    rust_p384_sign,
+    rust_p384_verify,
+    rust_p384_ecdh
+);
+
+/// Parse an RSA public key from DER (SubjectPublicKeyInfo or PKCS#1)
+///
+/// Parsing happens once here so verify/encrypt timings exclude it. Returns
+/// NULL if the buffer is neither encoding; release the key with
+/// `rust_rsa_public_key_free`.
+#[no_mangle]
+pub extern "C" fn rust_rsa_public_key_from_der(der: *const u8, len: usize) -> *mut RsaPublicKey {
+    let der = unsafe { std::slice::from_raw_parts(der, len) };
+    match RsaPublicKey::from_public_key_der(der).or_else(|_| RsaPublicKey::from_pkcs1_der(der)) {
+        Ok(key) => Box::into_raw(Box::new(key)),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Parse an RSA private key from DER (PKCS#8 or PKCS#1), including its CRT
+/// parameters
+///
+/// Returns NULL if the buffer is neither encoding; release the key with
+/// `rust_rsa_private_key_free`.
+#[no_mangle]
+pub extern "C" fn rust_rsa_private_key_from_der(der: *const u8, len: usize) -> *mut RsaPrivateKey {
+    let der = unsafe { std::slice::from_raw_parts(der, len) };
+    match RsaPrivateKey::from_pkcs8_der(der).or_else(|_| RsaPrivateKey::from_pkcs1_der(der)) {
+        Ok(key) => Box::into_raw(Box::new(key)),
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Free a key returned by `rust_rsa_public_key_from_der` (NULL is ignored)
+#[no_mangle]
+pub extern "C" fn rust_rsa_public_key_free(key: *mut RsaPublicKey) {
+    if !key.is_null() {
+        drop(unsafe { Box::from_raw(key) });
+    }
+}
+
+/// Free a key returned by `rust_rsa_private_key_from_der` (NULL is ignored)
+#[no_mangle]
+pub extern "C" fn rust_rsa_private_key_free(key: *mut RsaPrivateKey) {
+    if !key.is_null() {
+        drop(unsafe { Box::from_raw(key) });
+    }
+}
+
+/// Copy a variable-length RSA result into a caller buffer of `capacity`
+/// bytes, returning its length or -1 if it does not fit
+fn rsa_write_output(result: &[u8], output: *mut u8, capacity: usize) -> isize {
+    if result.len() > capacity {
+        return -1;
+    }
+
+    // Copy result to output buffer
+    unsafe {
+        std::ptr::copy_nonoverlapping(result.as_ptr(), output, result.len());
+    }
+    result.len() as isize
+}
+
+/// RSA PKCS#1 v1.5 sign over SHA-256(message) using rsa crate (blinded CRT)
+///
+/// Writes a modulus-sized signature. Returns its length, or -1 if signing
+/// fails or `capacity` is smaller than the modulus.
+#[no_mangle]
+pub extern "C" fn rust_rsa_pkcs1v15_sha256_sign(
+    key: *const RsaPrivateKey,
+    message: *const u8,
+    len: usize,
+    signature: *mut u8,
+    capacity: usize,
+) -> isize {
+    let key = unsafe { &*key };
+    let message = optional_slice(message, len);
+
+    // Hash the message, then run the private-key operation
+    let hashed = Sha256::digest(message);
+    match key.sign_with_rng(&mut OsRng, Pkcs1v15Sign::new::<Sha256>(), &hashed) {
+        Ok(result) => rsa_write_output(&result, signature, capacity),
+        Err(_) => -1,
+    }
+}
+
+/// RSA PKCS#1 v1.5 verify over SHA-256(message) using rsa crate
+///
+/// Returns 0 if valid, -1 otherwise.
+#[no_mangle]
+pub extern "C" fn rust_rsa_pkcs1v15_sha256_verify(
+    key: *const RsaPublicKey,
+    message: *const u8,
+    len: usize,
+    signature: *const u8,
+    signature_len: usize,
+) -> i32 {
+    let key = unsafe { &*key };
+    let message = optional_slice(message, len);
+    let signature = unsafe { std::slice::from_raw_parts(signature, signature_len) };
+
+    // Hash the message, then run the public-exponent operation
+    let hashed = Sha256::digest(message);
+    match key.verify(Pkcs1v15Sign::new::<Sha256>(), &hashed, signature) {
+        Ok(()) => 0,
+        Err(_) => -1,
+    }
+}
+
+/// RSA-PSS sign over SHA-256(message) using rsa crate (blinded CRT)
+///
+/// MGF1-SHA-256 with a random 32-byte salt. The private-key operation is
+/// blinded, as in `rust_rsa_pkcs1v15_sha256_sign`, so the two sign timings
+/// are comparable. Output and return value as for that function.
+#[no_mangle]
+pub extern "C" fn rust_rsa_pss_sha256_sign(
+    key: *const RsaPrivateKey,
+    message: *const u8,
+    len: usize,
+    signature: *mut u8,
+    capacity: usize,
+) -> isize {
+    let key = unsafe { &*key };
+    let message = optional_slice(message, len);
+
+    // Hash the message, then encode with a fresh salt and sign
+    let hashed = Sha256::digest(message);
+    match key.sign_with_rng(&mut OsRng, Pss::new_blinded::<Sha256>(), &hashed) {
+        Ok(result) => rsa_write_output(&result, signature, capacity),
+        Err(_) => -1,
+    }
+}
+
+/// RSA-PSS verify over SHA-256(message) using rsa crate
+///
+/// MGF1-SHA-256; the signer's salt length must be given in `salt_len` (32
+/// for `rust_rsa_pss_sha256_sign`, commonly also 0, 20 or the maximum).
+/// Returns 0 if valid, -1 otherwise (including a `salt_len` longer than the
+/// signature).
+#[no_mangle]
+pub extern "C" fn rust_rsa_pss_sha256_verify(
+    key: *const RsaPublicKey,
+    message: *const u8,
+    len: usize,
+    signature: *const u8,
+    signature_len: usize,
+    salt_len: usize,
+) -> i32 {
+    let key = unsafe { &*key };
+    let message = optional_slice(message, len);
+    let signature = unsafe { std::slice::from_raw_parts(signature, signature_len) };
+    // A salt cannot exceed the signature (and rsa's own bound check would overflow)
+    if salt_len > signature_len {
+        return -1;
+    }
+
+    // Hash the message, then run the public-exponent operation
+    let hashed = Sha256::digest(message);
+    match key.verify(Pss::new_with_salt::<Sha256>(salt_len), &hashed, signature) {
+        Ok(()) => 0,
+        Err(_) => -1,
+    }
+}
+
+/// RSA-OAEP encrypt using rsa crate (SHA-256 and MGF1-SHA-256, empty label)
+///
+/// Writes a modulus-sized ciphertext. Returns its length, or -1 if the
+/// plaintext is too long for the key or `capacity` is too small.
+#[no_mangle]
+pub extern "C" fn rust_rsa_oaep_sha256_encrypt(
+    key: *const RsaPublicKey,
+    plaintext: *const u8,
+    len: usize,
+    output: *mut u8,
+    capacity: usize,
+) -> isize {
+    let key = unsafe { &*key };
+    let plaintext = optional_slice(plaintext, len);
+
+    // Pad with a fresh seed, then run the public-exponent operation
+    match key.encrypt(&mut OsRng, Oaep::new::<Sha256>(), plaintext) {
+        Ok(result) => rsa_write_output(&result, output, capacity),
+        Err(_) => -1,
+    }
+}
+
+/// RSA-OAEP decrypt using rsa crate (SHA-256 and MGF1-SHA-256, empty label)
+///
+/// Returns the plaintext length, or -1 on a decryption error or if
+/// `capacity` is too small.
+#[no_mangle]
+pub extern "C" fn rust_rsa_oaep_sha256_decrypt(
+    key: *const RsaPrivateKey,
+    ciphertext: *const u8,
+    len: usize,
+    output: *mut u8,
+    capacity: usize,
+) -> isize {
+    let key = unsafe { &*key };
+    let ciphertext = unsafe { std::slice::from_raw_parts(ciphertext, len) };
+
+    // Run the blinded private-key operation, then check and strip padding
+    match key.decrypt_blinded(&mut OsRng, Oaep::new::<Sha256>(), ciphertext) {
+        Ok(result) => rsa_write_output(&result, output, capacity),
+        Err(_) => -1,
+    }
+}
+
+// ML-KEM exports share one body per operation; keys and ciphertexts are
+// decoded from the caller's fixed-size buffers on every call, as a caller
+// holding serialized keys would.
+macro_rules! ml_kem_exports {
+    (
+        $kem:ident, $name:literal,
+        $ek_len:literal, $dk_len:literal, $ct_len:literal,
+        $keypair:ident, $encaps:ident, $decaps:ident
+    ) => {
+        #[doc = concat!(
+                    $name, " key generation from a 64-byte d || z seed using ml-kem\n\n",
+                    "Writes a ", stringify!($ek_len), "-byte encapsulation key and a ",
+                    stringify!($dk_len), "-byte decapsulation key. Returns 0 on success, ",
+                    "-1 on failure."
+                )]
+        #[no_mangle]
+        pub extern "C" fn $keypair(
+            seed: *const u8,
+            encapsulation_key: *mut u8,
+            decapsulation_key: *mut u8,
+        ) -> i32 {
+            let seed = unsafe { std::slice::from_raw_parts(seed, 64) };
+            let (d, z) = seed.split_at(32);
+            let (Ok(d), Ok(z)) = (d.try_into(), z.try_into()) else {
+                return -1;
+            };
+
+            // Expand the seed into both keys, then encode
+            let (dk, ek) = $kem::generate_deterministic(d, z);
+            let ek = ek.as_bytes();
+            let dk = dk.as_bytes();
+
+            // Copy results to output buffers
+            unsafe {
+                std::ptr::copy_nonoverlapping(ek.as_ptr(), encapsulation_key, $ek_len);
+                std::ptr::copy_nonoverlapping(dk.as_ptr(), decapsulation_key, $dk_len);
+            }
+            0
+        }
+
+        #[doc = concat!(
+                    $name, " encapsulation with caller-supplied 32-byte randomness using ml-kem\n\n",
+                    "Writes a ", stringify!($ct_len), "-byte ciphertext and a 32-byte shared ",
+                    "secret. Returns 0 on success, -1 on failure. The encapsulation key is ",
+                    "not modulus-checked by ml-kem 0.2."
+                )]
+        #[no_mangle]
+        pub extern "C" fn $encaps(
+            encapsulation_key: *const u8,
+            randomness: *const u8,
+            ciphertext: *mut u8,
+            shared_secret: *mut u8,
+        ) -> i32 {
+            let ek = unsafe { std::slice::from_raw_parts(encapsulation_key, $ek_len) };
+            let m = unsafe { std::slice::from_raw_parts(randomness, 32) };
+            let (Ok(ek), Ok(m)) = (ek.try_into(), m.try_into()) else {
+                return -1;
+            };
+
+            // Decode the key, then encrypt the message and derive the secret
+            let ek = <$kem as KemCore>::EncapsulationKey::from_bytes(ek);
+            let Ok((ct, ss)) = ek.encapsulate_deterministic(m) else {
+                return -1;
+            };
+
+            // Copy results to output buffers
+            unsafe {
+                std::ptr::copy_nonoverlapping(ct.as_ptr(), ciphertext, $ct_len);
+                std::ptr::copy_nonoverlapping(ss.as_ptr(), shared_secret, 32);
+            }
+            0
+        }
+
+        #[doc = concat!(
+                    $name, " decapsulation using ml-kem\n\n",
+                    "Writes a 32-byte shared secret. A ciphertext that fails the ",
+                    "re-encryption check yields the implicit-rejection secret derived from z, ",
+                    "in constant time, rather than -1; -1 is reserved for internal failures."
+                )]
+        #[no_mangle]
+        pub extern "C" fn $decaps(
+            decapsulation_key: *const u8,
+            ciphertext: *const u8,
+            shared_secret: *mut u8,
+        ) -> i32 {
+            let dk = unsafe { std::slice::from_raw_parts(decapsulation_key, $dk_len) };
+            let ct = unsafe { std::slice::from_raw_parts(ciphertext, $ct_len) };
+            let (Ok(dk), Ok(ct)) = (dk.try_into(), ct.try_into()) else {
+                return -1;
+            };
+
+            // Decode the key, then decrypt, re-encrypt and select the secret
+            let dk = <$kem as KemCore>::DecapsulationKey::from_bytes(dk);
+            let Ok(ss) = dk.decapsulate(ct) else {
+                return -1;
+            };
+
+            // Copy result to output buffer
+            unsafe {
+                std::ptr::copy_nonoverlapping(ss.as_ptr(), shared_secret, 32);
+            }
+            0
+        }
+    };
+}
+
+ml_kem_exports!(
+    MlKem512,
+    "ML-KEM-512",
+    800,
+    1632,
+    768,
+    rust_ml_kem512_keypair,
+    rust_ml_kem512_encaps,
+    rust_ml_kem512_decaps
+);
+
+ml_kem_exports!(
+    MlKem768,
+    "ML-KEM-768",
+    1184,
+    2400,
+    1088,
+    rust_ml_kem768_keypair,
+    rust_ml_kem768_encaps,
+    rust_ml_kem768_decaps
+);
+
+ml_kem_exports!(
+    MlKem1024,
+    "ML-KEM-1024",
+    1568,
+    3168,
+    1568,
+    rust_ml_kem1024_keypair,
+    rust_ml_kem1024_encaps,
+    rust_ml_kem1024_decaps
+);
+
+/// One-shot RNG handing a caller-supplied 32-byte `rnd` to ml-dsa's hedged
+/// signer, so the system RNG stays out of sign timings
+struct MlDsaRnd<'a>(&'a [u8]);
+
+impl TryRng for MlDsaRnd<'_> {
+    type Error = ml_dsa::Error;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        let mut bytes = [0; 4];
+        self.try_fill_bytes(&mut bytes)?;
+        Ok(u32::from_le_bytes(bytes))
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        let mut bytes = [0; 8];
+        self.try_fill_bytes(&mut bytes)?;
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        if dst.len() > self.0.len() {
+            return Err(ml_dsa::Error::new());
+        }
+        let (taken, rest) = self.0.split_at(dst.len());
+        dst.copy_from_slice(taken);
+        self.0 = rest;
+        Ok(())
+    }
+}
+
+impl TryCryptoRng for MlDsaRnd<'_> {}
+
+// ML-DSA signing goes through an expanded key handle so the matrix expansion
+// stays out of sign timings; verification decodes the public key on every
+// call, as a certificate verifier would.
+macro_rules! ml_dsa_exports {
+    (
+        $params:ident, $name:literal, $public_key_len:literal, $signature_len:literal,
+        $public_key:ident, $key_new:ident, $key_free:ident,
+        $sign:ident, $sign_hedged:ident, $verify:ident
+    ) => {
+        #[doc = concat!(
+                    $name, " public key from a 32-byte seed using ml-dsa\n\n",
+                    "Writes the ", stringify!($public_key_len), "-byte encoded public key."
+                )]
+        #[no_mangle]
+        pub extern "C" fn $public_key(seed: *const u8, public_key: *mut u8) {
+            let seed = unsafe { &*(seed as *const [u8; 32]) };
+
+            // Expand the seed into a key pair, then encode the public half
+            let signing_key = ml_dsa::SigningKey::<$params>::from_seed(&(*seed).into());
+            let result = ml_dsa::Keypair::verifying_key(&signing_key).encode();
+
+            // Copy result to output buffer
+            unsafe {
+                std::ptr::copy_nonoverlapping(result.as_ptr(), public_key, $public_key_len);
+            }
+        }
+
+        #[doc = concat!(
+                    "Expand a 32-byte ", $name, " seed once; release it with `",
+                    stringify!($key_free), "`"
+                )]
+        #[no_mangle]
+        pub extern "C" fn $key_new(seed: *const u8) -> *mut ml_dsa::ExpandedSigningKey<$params> {
+            let seed = unsafe { &*(seed as *const [u8; 32]) };
+            let key = ml_dsa::ExpandedSigningKey::<$params>::from_seed(&(*seed).into());
+            Box::into_raw(Box::new(key))
+        }
+
+        #[doc = concat!("Free a key returned by `", stringify!($key_new), "` (NULL is ignored)")]
+        #[no_mangle]
+        pub extern "C" fn $key_free(key: *mut ml_dsa::ExpandedSigningKey<$params>) {
+            if !key.is_null() {
+                drop(unsafe { Box::from_raw(key) });
+            }
+        }
+
+        #[doc = concat!(
+                    $name, " deterministic sign using ml-dsa\n\n",
+                    "Writes a ", stringify!($signature_len), "-byte signature. `context` may ",
+                    "be NULL when `context_len` is 0. Returns 0 on success, -1 if the ",
+                    "context is longer than 255 bytes."
+                )]
+        #[no_mangle]
+        pub extern "C" fn $sign(
+            key: *const ml_dsa::ExpandedSigningKey<$params>,
+            message: *const u8,
+            len: usize,
+            context: *const u8,
+            context_len: usize,
+            signature: *mut u8,
+        ) -> i32 {
+            let key = unsafe { &*key };
+            let message = optional_slice(message, len);
+            let context = optional_slice(context, context_len);
+
+            // Sign with an all-zero rnd
+            let Ok(result) = key.sign_deterministic(message, context) else {
+                return -1;
+            };
+            let result = result.encode();
+
+            // Copy result to output buffer
+            unsafe {
+                std::ptr::copy_nonoverlapping(result.as_ptr(), signature, $signature_len);
+            }
+            0
+        }
+
+        #[doc = concat!(
+                    $name, " hedged sign with caller-supplied 32-byte rnd using ml-dsa\n\n",
+                    "Same output and return value as `", stringify!($sign), "`; fresh ",
+                    "random `rnd` per call gives the FIPS 204 hedged variant without timing ",
+                    "the system RNG."
+                )]
+        #[no_mangle]
+        pub extern "C" fn $sign_hedged(
+            key: *const ml_dsa::ExpandedSigningKey<$params>,
+            message: *const u8,
+            len: usize,
+            context: *const u8,
+            context_len: usize,
+            rnd: *const u8,
+            signature: *mut u8,
+        ) -> i32 {
+            let key = unsafe { &*key };
+            let message = optional_slice(message, len);
+            let context = optional_slice(context, context_len);
+            let rnd = unsafe { std::slice::from_raw_parts(rnd, 32) };
+
+            // Sign with the supplied rnd in place of fresh randomness
+            let Ok(result) = key.sign_randomized(message, context, &mut MlDsaRnd(rnd)) else {
+                return -1;
+            };
+            let result = result.encode();
+
+            // Copy result to output buffer
+            unsafe {
+                std::ptr::copy_nonoverlapping(result.as_ptr(), signature, $signature_len);
+            }
+            0
+        }
+
+        #[doc = concat!(
+                    $name, " verify using ml-dsa\n\n",
+                    "Takes a ", stringify!($public_key_len), "-byte public key and a ",
+                    stringify!($signature_len), "-byte signature. Returns 0 if valid, ",
+                    "-1 otherwise."
+                )]
+        #[no_mangle]
+        pub extern "C" fn $verify(
+            public_key: *const u8,
+            message: *const u8,
+            len: usize,
+            context: *const u8,
+            context_len: usize,
+            signature: *const u8,
+        ) -> i32 {
+            let public_key = unsafe { std::slice::from_raw_parts(public_key, $public_key_len) };
+            let message = optional_slice(message, len);
+            let context = optional_slice(context, context_len);
+            let signature = unsafe { std::slice::from_raw_parts(signature, $signature_len) };
+
+            // Decode the key and signature, then check
+            let (Ok(public_key), Ok(signature)) = (public_key.try_into(), signature.try_into())
+            else {
+                return -1;
+            };
+            let verifying_key = ml_dsa::VerifyingKey::<$params>::decode(public_key);
+            let Some(signature) = ml_dsa::Signature::<$params>::decode(signature) else {
+                return -1;
+            };
+            if verifying_key.verify_with_context(message, context, &signature) {
+                0
+            } else {
+                -1
+            }
+        }
+    };
+}
+
+ml_dsa_exports!(
+    MlDsa44,
+    "ML-DSA-44",
+    1312,
+    2420,
+    rust_ml_dsa44_public_key,
+    rust_ml_dsa44_key_new,
+    rust_ml_dsa44_key_free,
+    rust_ml_dsa44_sign,
+    rust_ml_dsa44_sign_hedged,
+    rust_ml_dsa44_verify
+);
+
+ml_dsa_exports!(
+    MlDsa65,
+    "ML-DSA-65",
+    1952,
+    3309,
+    rust_ml_dsa65_public_key,
+    rust_ml_dsa65_key_new,
+    rust_ml_dsa65_key_free,
+    rust_ml_dsa65_sign,
+    rust_ml_dsa65_sign_hedged,
+    rust_ml_dsa65_verify
+);
+
+ml_dsa_exports!(
+    MlDsa87,
+    "ML-DSA-87",
+    2592,
+    4627,
+    rust_ml_dsa87_public_key,
+    rust_ml_dsa87_key_new,
+    rust_ml_dsa87_key_free,
+    rust_ml_dsa87_sign,
+    rust_ml_dsa87_sign_hedged,
+    rust_ml_dsa87_verify
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unhex(s: &str) -> Vec<u8> {
+        (0..s.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    // Feed `data` through a streaming universal hash in `chunk`-byte pieces
+    // and return the tag
+    fn universal_hash_streamed<U>(
+        ctx: *mut UniversalHashStream<U>,
+        update: extern "C" fn(*mut UniversalHashStream<U>, *const u8, usize),
+        finalize: extern "C" fn(*mut UniversalHashStream<U>, *mut u8),
+        data: &[u8],
+        chunk: usize,
+    ) -> [u8; 16] {
+        for piece in data.chunks(chunk) {
+            update(ctx, piece.as_ptr(), piece.len());
+        }
+        let mut tag = [0u8; 16];
+        finalize(ctx, tag.as_mut_ptr());
+        tag
+    }
+
+    #[test]
+    fn poly1305_rfc8439_vector() {
+        let key = unhex("85d6be7857556d337f4452fe42d506a80103808afb0db2fd4abff6af4149f51b");
+        let message = b"Cryptographic Forum Research Group";
+        let mut tag = [0u8; 16];
+        rust_poly1305(
+            key.as_ptr(),
+            message.as_ptr(),
+            message.len(),
+            tag.as_mut_ptr(),
+        );
+        assert_eq!(tag.to_vec(), unhex("a8061dc1305136c6c22b8baf0c0127a9"));
+    }
+
+    #[test]
+    fn universal_hash_streaming_matches_one_shot() {
+        let key: Vec<u8> = (0..32).collect();
+        let data: Vec<u8> = (0..100u8).map(|i| i.wrapping_mul(37)).collect();
+
+        for chunk in [1, 15, 16, 17] {
+            let mut expected = [0u8; 16];
+
+            rust_poly1305(
+                key.as_ptr(),
+                data.as_ptr(),
+                data.len(),
+                expected.as_mut_ptr(),
+            );
+            let ctx = rust_poly1305_new(key.as_ptr());
+            for _ in 0..2 {
+                let tag = universal_hash_streamed(
+                    ctx,
+                    rust_poly1305_update,
+                    rust_poly1305_finalize,
+                    &data,
+                    chunk,
+                );
+                assert_eq!(tag, expected, "poly1305 chunk {chunk}");
+            }
+            rust_poly1305_free(ctx);
+
+            rust_ghash(
+                key.as_ptr(),
+                data.as_ptr(),
+                data.len(),
+                expected.as_mut_ptr(),
+            );
+            let ctx = rust_ghash_new(key.as_ptr());
+            for _ in 0..2 {
+                let tag = universal_hash_streamed(
+                    ctx,
+                    rust_ghash_update,
+                    rust_ghash_finalize,
+                    &data,
+                    chunk,
+                );
+                assert_eq!(tag, expected, "ghash chunk {chunk}");
+            }
+            rust_ghash_free(ctx);
+
+            rust_polyval(
+                key.as_ptr(),
+                data.as_ptr(),
+                data.len(),
+                expected.as_mut_ptr(),
+            );
+            let ctx = rust_polyval_new(key.as_ptr());
+            for _ in 0..2 {
+                let tag = universal_hash_streamed(
+                    ctx,
+                    rust_polyval_update,
+                    rust_polyval_finalize,
+                    &data,
+                    chunk,
+                );
+                assert_eq!(tag, expected, "polyval chunk {chunk}");
+            }
+            rust_polyval_free(ctx);
+        }
+    }
+
+    fn ed25519_batch(
+        public_keys: &[[u8; 32]],
+        messages: &[&[u8]],
+        signatures: &[[u8; 64]],
+    ) -> (i32, usize) {
+        let message_ptrs: Vec<*const u8> = messages.iter().map(|m| m.as_ptr()).collect();
+        let message_lens: Vec<usize> = messages.iter().map(|m| m.len()).collect();
+        let mut failed_index = usize::MAX;
+        let status = rust_ed25519_verify_batch(
+            message_ptrs.as_ptr(),
+            message_lens.as_ptr(),
+            public_keys.concat().as_ptr(),
+            signatures.concat().as_ptr(),
+            public_keys.len(),
+            &mut failed_index,
+        );
+        (status, failed_index)
+    }
+
+    #[test]
+    fn ed25519_batch_reports_failing_index() {
+        let messages: [&[u8]; 3] = [b"first", b"second", b""];
+        let mut public_keys = [[0u8; 32]; 3];
+        let mut signatures = [[0u8; 64]; 3];
+        for i in 0..3 {
+            let seed = [i as u8 + 1; 32];
+            rust_ed25519_keypair_from_seed(seed.as_ptr(), public_keys[i].as_mut_ptr());
+            rust_ed25519_sign(
+                seed.as_ptr(),
+                messages[i].as_ptr(),
+                messages[i].len(),
+                signatures[i].as_mut_ptr(),
+            );
+        }
+        assert_eq!(ed25519_batch(&public_keys, &messages, &signatures).0, 0);
+
+        let mut tampered = signatures;
+        tampered[1][0] ^= 1;
+        assert_eq!(ed25519_batch(&public_keys, &messages, &tampered), (-1, 1));
+
+        // Identity public key with R = identity, s = 0 passes the bare batch
+        // equation; it must be rejected here as the single verify rejects it
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        let mut forged = [0u8; 64];
+        forged[0] = 1;
+        assert_eq!(
+            rust_ed25519_verify(identity.as_ptr(), messages[2].as_ptr(), 0, forged.as_ptr()),
+            -1
+        );
+        public_keys[2] = identity;
+        signatures[2] = forged;
+        assert_eq!(ed25519_batch(&public_keys, &messages, &signatures), (-1, 2));
+    }
+
+    #[test]
+    fn secp256k1_recover_matches_signer_including_high_s() {
+        // Secret 1, so the public key is the generator
+        let mut secret = [0u8; 32];
+        secret[31] = 1;
+        let generator = unhex(concat!(
+            "0479be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8"
+        ));
+        let prehash = [0x42u8; 32];
+        let mut signature = [0u8; 65];
+        assert_eq!(
+            rust_secp256k1_sign_prehash(secret.as_ptr(), prehash.as_ptr(), signature.as_mut_ptr()),
+            0
+        );
+
+        let recover = |signature: &[u8; 65]| {
+            let mut public_key = [0u8; 65];
+            let status = rust_secp256k1_recover(
+                prehash.as_ptr(),
+                signature.as_ptr(),
+                public_key.as_mut_ptr(),
+            );
+            (status, public_key.to_vec())
+        };
+        assert_eq!(recover(&signature), (0, generator.clone()));
+
+        let mut ethereum_v = signature;
+        ethereum_v[64] += 27;
+        assert_eq!(recover(&ethereum_v), (0, generator.clone()));
+
+        // (r, n - s, v ^ 1) is the high-S twin of the same signature
+        let low = k256::ecdsa::Signature::from_slice(&signature[..64]).unwrap();
+        let (r, s) = low.split_scalars();
+        let high = k256::ecdsa::Signature::from_scalars(r.to_bytes(), (-*s).to_bytes()).unwrap();
+        let mut high_s = signature;
+        high_s[..64].copy_from_slice(&high.to_bytes());
+        high_s[64] ^= 1;
+        assert_eq!(recover(&high_s), (0, generator.clone()));
+
+        let mut bad_v = signature;
+        bad_v[64] = 2;
+        assert_eq!(recover(&bad_v).0, -1);
+    }
+
+    #[test]
+    fn ml_dsa_public_keys_match_lamps_examples() {
+        // Seed 00 01 .. 1f; digests of the public keys in the IETF LAMPS
+        // dilithium-certificates examples
+        let seed: Vec<u8> = (0..32).collect();
+        let mut public_key = [0u8; 2592];
+        rust_ml_dsa44_public_key(seed.as_ptr(), public_key.as_mut_ptr());
+        assert_eq!(
+            Sha256::digest(&public_key[..1312]).to_vec(),
+            unhex("9f107644c1084526af3bc8098680b05499a2325a644e388fb4f970e058d19d46")
+        );
+        rust_ml_dsa65_public_key(seed.as_ptr(), public_key.as_mut_ptr());
+        assert_eq!(
+            Sha256::digest(&public_key[..1952]).to_vec(),
+            unhex("d666806e11cee19a7c989f7445f90dd419cf4d2d51db8c0fdb4c0f0a542238c9")
+        );
+        rust_ml_dsa87_public_key(seed.as_ptr(), public_key.as_mut_ptr());
+        assert_eq!(
+            Sha256::digest(public_key).to_vec(),
+            unhex("91dc389cfaa01470b7f66eee45a4ae9026d154817c754dfe22298b3fa241ffcd")
+        );
+    }
+
+    #[test]
+    fn ml_dsa_hedged_sign_frames_message_per_fips_204() {
+        let seed = [7u8; 32];
+        let message = b"certificate tbs";
+        let context = b"ctx";
+        let rnd = [0x5au8; 32];
+        let mut public_key = [0u8; 1952];
+        rust_ml_dsa65_public_key(seed.as_ptr(), public_key.as_mut_ptr());
+        let key = rust_ml_dsa65_key_new(seed.as_ptr());
+
+        let mut hedged = [0u8; 3309];
+        let status = rust_ml_dsa65_sign_hedged(
+            key,
+            message.as_ptr(),
+            message.len(),
+            context.as_ptr(),
+            context.len(),
+            rnd.as_ptr(),
+            hedged.as_mut_ptr(),
+        );
+        assert_eq!(status, 0);
+
+        // M' = 0 || len(ctx) || ctx || M, signed by ML-DSA.Sign_internal
+        let header = [0, context.len() as u8];
+        let expected = unsafe { &*key }
+            .sign_internal(&[&header, context, message], &rnd.into())
+            .encode();
+        assert_eq!(hedged[..], expected[..]);
+
+        let verify = |context: &[u8], signature: &[u8]| {
+            rust_ml_dsa65_verify(
+                public_key.as_ptr(),
+                message.as_ptr(),
+                message.len(),
+                context.as_ptr(),
+                context.len(),
+                signature.as_ptr(),
+            )
+        };
+        assert_eq!(verify(context, &hedged), 0);
+        assert_eq!(verify(b"", &hedged), -1);
+
+        // The deterministic variant is the hedged one with rnd = 0
+        let mut deterministic = [0u8; 3309];
+        let mut zero_rnd = [0u8; 3309];
+        let status = rust_ml_dsa65_sign(
+            key,
+            message.as_ptr(),
+            message.len(),
+            context.as_ptr(),
+            context.len(),
+            deterministic.as_mut_ptr(),
+        );
+        assert_eq!(status, 0);
+        let status = rust_ml_dsa65_sign_hedged(
+            key,
+            message.as_ptr(),
+            message.len(),
+            context.as_ptr(),
+            context.len(),
+            [0u8; 32].as_ptr(),
+            zero_rnd.as_mut_ptr(),
+        );
+        assert_eq!(status, 0);
+        assert_eq!(deterministic[..], zero_rnd[..]);
+        assert_eq!(verify(context, &deterministic), 0);
+
+        rust_ml_dsa65_key_free(key);
+    }
+
+    type Blake2Mac = extern "C" fn(
+        *const u8,
+        usize,
+        *const u8,
+        usize,
+        *const u8,
+        usize,
+        *const u8,
+        usize,
+        *mut u8,
+    ) -> i32;
+
+    #[test]
+    fn blake2_mac_rejects_oversized_salt_and_personal() {
+        let key = [1u8; 32];
+        let long = [2u8; 17];
+        let mut output = [0u8; 64];
+        let mac = |mac: Blake2Mac, salt_len: usize, personal_len: usize, output: &mut [u8; 64]| {
+            mac(
+                key.as_ptr(),
+                key.len(),
+                long.as_ptr(),
+                salt_len,
+                long.as_ptr(),
+                personal_len,
+                b"data".as_ptr(),
+                4,
+                output.as_mut_ptr(),
+            )
+        };
+
+        assert_eq!(mac(rust_blake2b512_mac, 16, 16, &mut output), 0);
+        assert_eq!(mac(rust_blake2b512_mac, 17, 0, &mut output), -1);
+        assert_eq!(mac(rust_blake2b256_mac, 0, 17, &mut output), -1);
+        assert_eq!(mac(rust_blake2s256_mac, 8, 8, &mut output), 0);
+        assert_eq!(mac(rust_blake2s256_mac, 9, 0, &mut output), -1);
+        assert_eq!(mac(rust_blake2s256_mac, 0, 9, &mut output), -1);
+    }
+
+    #[test]
+    fn ml_kem_decaps_implicit_rejection() {
+        let seed: Vec<u8> = (0..64).collect();
+        let mut encapsulation_key = [0u8; 1184];
+        let mut decapsulation_key = [0u8; 2400];
+        let status = rust_ml_kem768_keypair(
+            seed.as_ptr(),
+            encapsulation_key.as_mut_ptr(),
+            decapsulation_key.as_mut_ptr(),
+        );
+        assert_eq!(status, 0);
+
+        let mut ciphertext = [0u8; 1088];
+        let mut shared = [0u8; 32];
+        let status = rust_ml_kem768_encaps(
+            encapsulation_key.as_ptr(),
+            [0x33u8; 32].as_ptr(),
+            ciphertext.as_mut_ptr(),
+            shared.as_mut_ptr(),
+        );
+        assert_eq!(status, 0);
+
+        let decaps = |ciphertext: &[u8; 1088]| {
+            let mut secret = [0u8; 32];
+            let status = rust_ml_kem768_decaps(
+                decapsulation_key.as_ptr(),
+                ciphertext.as_ptr(),
+                secret.as_mut_ptr(),
+            );
+            (status, secret)
+        };
+        assert_eq!(decaps(&ciphertext), (0, shared));
+
+        // A tampered ciphertext yields the rejection key J(z || c) = SHAKE256(z || c),
+        // 32 bytes, with z the second half of the seed
+        ciphertext[100] ^= 0x01;
+        let (status, rejected) = decaps(&ciphertext);
+        assert_eq!(status, 0);
+        assert_ne!(rejected, shared);
+        let j_input = [&seed[32..], &ciphertext[..]].concat();
+        let mut expected = [0u8; 32];
+        rust_shake256(j_input.as_ptr(), j_input.len(), expected.as_mut_ptr(), 32);
+        assert_eq!(rejected, expected);
+    }
+}